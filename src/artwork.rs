@@ -0,0 +1,209 @@
+//! Resolves the staged taste selection into the artwork a build would
+//! produce, off the render thread so lookup latency can't stall the
+//! 100ms `event::poll` loop in `main::run`. The main loop posts the
+//! current `MergedTasteSelection` into a single-slot mailbox whenever it
+//! changes; a dedicated worker thread blocks on that mailbox, resolves
+//! whatever is waiting, and publishes the result into a shared cell that
+//! `ui::draw` reads non-blockingly every frame. Posting a new selection
+//! before the worker has picked up the previous one just overwrites it,
+//! so rapid profile switching only ever resolves the most recent choice.
+//!
+//! There's no real artwork backend anywhere in this codebase yet — see
+//! `cli::run`'s `Command::Build`, which already stops at reporting the
+//! resolved selection instead of writing wallpapers. `resolve` below is
+//! the same kind of stand-in; the worker/mailbox/status plumbing around
+//! it is the real, reusable piece.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use crate::app::MergedTasteSelection;
+
+/// What a resolved selection turned into artwork-wise. Stands in for real
+/// candidate artwork ids until a resolver exists to produce them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResolvedArtwork {
+    pub profile_names: Vec<String>,
+    pub keyword_count: usize,
+    /// Identifies `image` for the preview cache — two resolutions of the
+    /// same selection share an id so a repaint doesn't re-downsample.
+    pub image_id: u64,
+    pub image: RgbImage,
+}
+
+/// A decoded RGB raster, row-major, no alpha channel. Until a real artwork
+/// backend exists (see the module doc above), `resolve` fills this in with
+/// `synthetic_image` rather than leaving callers with nothing to preview.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RgbImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<(u8, u8, u8)>,
+}
+
+impl Default for RgbImage {
+    fn default() -> Self {
+        RgbImage { width: 0, height: 0, pixels: Vec::new() }
+    }
+}
+
+impl RgbImage {
+    pub fn pixel(&self, x: u32, y: u32) -> (u8, u8, u8) {
+        self.pixels[(y * self.width + x) as usize]
+    }
+}
+
+/// A stand-in "candidate wallpaper": a diagonal gradient whose hue is
+/// derived from `seed`, so the same staged selection always previews the
+/// same way and different selections are visibly distinct.
+const SYNTHETIC_SIZE: u32 = 48;
+
+fn synthetic_image(seed: u64) -> RgbImage {
+    let hue = (seed % 360) as f64;
+    let mut pixels = Vec::with_capacity((SYNTHETIC_SIZE * SYNTHETIC_SIZE) as usize);
+    for y in 0..SYNTHETIC_SIZE {
+        for x in 0..SYNTHETIC_SIZE {
+            let t = (x + y) as f64 / (2 * (SYNTHETIC_SIZE - 1)) as f64;
+            pixels.push(hsv_to_rgb(hue, 0.55, 0.35 + 0.5 * t));
+        }
+    }
+    RgbImage { width: SYNTHETIC_SIZE, height: SYNTHETIC_SIZE, pixels }
+}
+
+fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
+    let c = v * s;
+    let h_prime = h / 60.0;
+    let x = c * (1.0 - (h_prime % 2.0 - 1.0).abs());
+    let (r1, g1, b1) = match h_prime as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = v - c;
+    (
+        ((r1 + m) * 255.0).round() as u8,
+        ((g1 + m) * 255.0).round() as u8,
+        ((b1 + m) * 255.0).round() as u8,
+    )
+}
+
+/// Box-downsample `src` to exactly `width` x `height` pixels — used to
+/// shrink a resolved artwork to fit a terminal preview pane's cell grid
+/// (two output pixels per cell row via half-block glyphs).
+pub fn downsample(src: &RgbImage, width: u32, height: u32) -> RgbImage {
+    if src.width == 0 || src.height == 0 || width == 0 || height == 0 {
+        return RgbImage { width, height, pixels: vec![(0, 0, 0); (width * height) as usize] };
+    }
+    let mut pixels = Vec::with_capacity((width * height) as usize);
+    for y in 0..height {
+        let sy0 = y * src.height / height;
+        let sy1 = ((y + 1) * src.height / height).max(sy0 + 1).min(src.height);
+        for x in 0..width {
+            let sx0 = x * src.width / width;
+            let sx1 = ((x + 1) * src.width / width).max(sx0 + 1).min(src.width);
+            let mut sum = (0u32, 0u32, 0u32);
+            let mut count = 0u32;
+            for sy in sy0..sy1 {
+                for sx in sx0..sx1 {
+                    let (r, g, b) = src.pixel(sx, sy);
+                    sum.0 += r as u32;
+                    sum.1 += g as u32;
+                    sum.2 += b as u32;
+                    count += 1;
+                }
+            }
+            pixels.push(((sum.0 / count) as u8, (sum.1 / count) as u8, (sum.2 / count) as u8));
+        }
+    }
+    RgbImage { width, height, pixels }
+}
+
+/// Latest outcome of a lookup, for the render loop to read each frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ArtworkStatus {
+    #[default]
+    Loading,
+    Ready(ResolvedArtwork),
+}
+
+/// Single-slot handoff from the render loop to the worker: only the most
+/// recently submitted selection is ever kept, so a burst of profile
+/// toggles collapses into one lookup instead of queuing one per toggle.
+struct Mailbox {
+    pending: Mutex<Option<MergedTasteSelection>>,
+    posted: Condvar,
+}
+
+/// Handle the render loop holds: two `Arc`s shared with the worker thread.
+/// It does not implement `Clone`.
+pub struct ArtworkWorker {
+    mailbox: Arc<Mailbox>,
+    status: Arc<Mutex<ArtworkStatus>>,
+}
+
+impl ArtworkWorker {
+    /// Spawn the background resolver thread and return a handle to it.
+    /// The thread is detached — it parks on the mailbox's condvar for the
+    /// lifetime of the process, which is fine since there's one worker
+    /// per TUI session and it exits with the process.
+    pub fn spawn() -> Self {
+        let mailbox = Arc::new(Mailbox {
+            pending: Mutex::new(None),
+            posted: Condvar::new(),
+        });
+        let status = Arc::new(Mutex::new(ArtworkStatus::Loading));
+
+        let worker_mailbox = Arc::clone(&mailbox);
+        let worker_status = Arc::clone(&status);
+        thread::spawn(move || run(worker_mailbox, worker_status));
+
+        Self { mailbox, status }
+    }
+
+    /// Submit the current staged selection for resolution. Call this
+    /// whenever the stage changes (a toggle, or entering the build
+    /// wizard's confirm step) — never blocks on the lookup itself, only
+    /// on the mailbox mutex long enough to swap a value in.
+    pub fn submit(&self, selection: MergedTasteSelection) {
+        let mut pending = self.mailbox.pending.lock().unwrap();
+        *pending = Some(selection);
+        self.mailbox.posted.notify_one();
+    }
+
+    /// The most recently finished lookup, or `Loading` before the first
+    /// one completes. Never blocks on the worker thread.
+    pub fn latest(&self) -> ArtworkStatus {
+        self.status.lock().unwrap().clone()
+    }
+}
+
+fn run(mailbox: Arc<Mailbox>, status: Arc<Mutex<ArtworkStatus>>) {
+    loop {
+        let selection = {
+            let mut pending = mailbox.pending.lock().unwrap();
+            while pending.is_none() {
+                pending = mailbox.posted.wait(pending).unwrap();
+            }
+            pending.take().unwrap()
+        };
+        *status.lock().unwrap() = ArtworkStatus::Ready(resolve(selection));
+    }
+}
+
+fn resolve(selection: MergedTasteSelection) -> ResolvedArtwork {
+    let mut hasher = DefaultHasher::new();
+    selection.names.hash(&mut hasher);
+    selection.keywords.hash(&mut hasher);
+    let image_id = hasher.finish();
+    ResolvedArtwork {
+        profile_names: selection.names,
+        keyword_count: selection.keywords.len(),
+        image_id,
+        image: synthetic_image(image_id),
+    }
+}
@@ -0,0 +1,351 @@
+use std::fmt;
+
+use anyhow::Result;
+use rusqlite::Connection;
+
+use crate::app::{DisplayProfile, TasteProfile};
+use crate::db;
+
+/// A portable bundle of taste/display profiles (with keywords and artists),
+/// serialized as a small TOML-like format — `[[taste]]`/`[[display]]` tables
+/// of flat fields — the same hand-rolled-parser approach as the theme
+/// overlay file, since a handful of fields and one string list don't need a
+/// real TOML parser.
+#[derive(Debug, Clone, Default)]
+pub struct Bundle {
+    pub tastes: Vec<TasteRecord>,
+    pub displays: Vec<DisplayRecord>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TasteRecord {
+    pub name: String,
+    pub date_start: Option<i64>,
+    pub date_end: Option<i64>,
+    pub is_public_domain: bool,
+    pub keywords: Vec<String>,
+    pub artists: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DisplayRecord {
+    pub name: String,
+    pub wallpaper_color: String,
+    pub frame_style: String,
+    pub orientation: String,
+    pub aspect_ratio: String,
+}
+
+impl From<&TasteProfile> for TasteRecord {
+    fn from(p: &TasteProfile) -> Self {
+        Self {
+            name: p.name.clone(),
+            date_start: p.date_start,
+            date_end: p.date_end,
+            is_public_domain: p.is_public_domain,
+            keywords: p.keywords.clone(),
+            artists: p.artists.clone(),
+        }
+    }
+}
+
+impl From<&DisplayProfile> for DisplayRecord {
+    fn from(p: &DisplayProfile) -> Self {
+        Self {
+            name: p.name.clone(),
+            wallpaper_color: p.wallpaper_color.clone(),
+            frame_style: p.frame_style.clone(),
+            orientation: p.orientation.clone(),
+            aspect_ratio: p.aspect_ratio.clone(),
+        }
+    }
+}
+
+/// Malformed bundle text, or a field that fails validation on import.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BundleError(pub String);
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Serialize profiles into bundle text, suitable for writing to a file and
+/// sharing. Inverse of [`parse`].
+pub fn export(tastes: &[TasteProfile], displays: &[DisplayProfile]) -> String {
+    let mut out = String::new();
+    for t in tastes {
+        out.push_str("[[taste]]\n");
+        out.push_str(&format!("name = \"{}\"\n", escape(&t.name)));
+        if let Some(v) = t.date_start {
+            out.push_str(&format!("date_start = {}\n", v));
+        }
+        if let Some(v) = t.date_end {
+            out.push_str(&format!("date_end = {}\n", v));
+        }
+        out.push_str(&format!("is_public_domain = {}\n", t.is_public_domain));
+        let quoted: Vec<String> = t
+            .keywords
+            .iter()
+            .map(|k| format!("\"{}\"", escape(k)))
+            .collect();
+        out.push_str(&format!("keywords = [{}]\n", quoted.join(", ")));
+        let quoted: Vec<String> = t
+            .artists
+            .iter()
+            .map(|a| format!("\"{}\"", escape(a)))
+            .collect();
+        out.push_str(&format!("artists = [{}]\n", quoted.join(", ")));
+        out.push('\n');
+    }
+    for d in displays {
+        out.push_str("[[display]]\n");
+        out.push_str(&format!("name = \"{}\"\n", escape(&d.name)));
+        out.push_str(&format!(
+            "wallpaper_color = \"{}\"\n",
+            escape(&d.wallpaper_color)
+        ));
+        out.push_str(&format!("frame_style = \"{}\"\n", escape(&d.frame_style)));
+        out.push_str(&format!("orientation = \"{}\"\n", escape(&d.orientation)));
+        out.push_str(&format!("aspect_ratio = \"{}\"\n", escape(&d.aspect_ratio)));
+        out.push('\n');
+    }
+    out
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("\\\"", "\"").replace("\\\\", "\\")
+}
+
+/// Parse bundle text written by [`export`]. Each `[[taste]]`/`[[display]]`
+/// line opens a new record; `key = value` lines belong to whichever record
+/// was most recently opened.
+pub fn parse(text: &str) -> Result<Bundle, BundleError> {
+    let mut bundle = Bundle::default();
+    let mut section: Option<&str> = None;
+    let mut taste: Option<TasteRecord> = None;
+    let mut display: Option<DisplayRecord> = None;
+
+    for raw_line in text.lines() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line == "[[taste]]" {
+            if let Some(t) = taste.take() {
+                bundle.tastes.push(t);
+            }
+            if let Some(d) = display.take() {
+                bundle.displays.push(d);
+            }
+            section = Some("taste");
+            taste = Some(TasteRecord {
+                name: String::new(),
+                date_start: None,
+                date_end: None,
+                is_public_domain: false,
+                keywords: vec![],
+                artists: vec![],
+            });
+            continue;
+        }
+        if line == "[[display]]" {
+            if let Some(t) = taste.take() {
+                bundle.tastes.push(t);
+            }
+            if let Some(d) = display.take() {
+                bundle.displays.push(d);
+            }
+            section = Some("display");
+            display = Some(DisplayRecord {
+                name: String::new(),
+                wallpaper_color: "#FFFFFF".to_string(),
+                frame_style: String::new(),
+                orientation: "horizontal".to_string(),
+                aspect_ratio: "16:9".to_string(),
+            });
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(BundleError(format!("malformed line: {}", raw_line)));
+        };
+        let key = key.trim();
+        let value = value.trim();
+        match section {
+            Some("taste") => {
+                let t = taste.as_mut().expect("taste section open");
+                match key {
+                    "name" => t.name = unescape(value.trim_matches('"')),
+                    "date_start" => t.date_start = value.parse::<i64>().ok(),
+                    "date_end" => t.date_end = value.parse::<i64>().ok(),
+                    "is_public_domain" => t.is_public_domain = value == "true",
+                    "keywords" => t.keywords = parse_string_list(value),
+                    "artists" => t.artists = parse_string_list(value),
+                    _ => {}
+                }
+            }
+            Some("display") => {
+                let d = display.as_mut().expect("display section open");
+                match key {
+                    "name" => d.name = unescape(value.trim_matches('"')),
+                    "wallpaper_color" => d.wallpaper_color = unescape(value.trim_matches('"')),
+                    "frame_style" => d.frame_style = unescape(value.trim_matches('"')),
+                    "orientation" => d.orientation = unescape(value.trim_matches('"')),
+                    "aspect_ratio" => d.aspect_ratio = unescape(value.trim_matches('"')),
+                    _ => {}
+                }
+            }
+            None => {
+                return Err(BundleError(format!(
+                    "field outside any [[taste]]/[[display]] section: {}",
+                    raw_line
+                )))
+            }
+        }
+    }
+    if let Some(t) = taste.take() {
+        bundle.tastes.push(t);
+    }
+    if let Some(d) = display.take() {
+        bundle.displays.push(d);
+    }
+
+    for t in &bundle.tastes {
+        if t.name.is_empty() {
+            return Err(BundleError("taste profile missing a name".to_string()));
+        }
+    }
+    for d in &bundle.displays {
+        validate_display(d)?;
+    }
+    Ok(bundle)
+}
+
+fn parse_string_list(value: &str) -> Vec<String> {
+    let inner = value.trim().trim_start_matches('[').trim_end_matches(']');
+    inner
+        .split(',')
+        .map(|s| unescape(s.trim().trim_matches('"')))
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn validate_display(record: &DisplayRecord) -> Result<(), BundleError> {
+    if record.name.is_empty() {
+        return Err(BundleError("display profile missing a name".to_string()));
+    }
+    if !matches!(record.orientation.as_str(), "horizontal" | "vertical") {
+        return Err(BundleError(format!(
+            "display \"{}\": orientation must be horizontal or vertical, got \"{}\"",
+            record.name, record.orientation
+        )));
+    }
+    if !is_hex_color(&record.wallpaper_color) {
+        return Err(BundleError(format!(
+            "display \"{}\": wallpaper_color \"{}\" is not a #RRGGBB hex color",
+            record.name, record.wallpaper_color
+        )));
+    }
+    if parse_aspect_ratio(&record.aspect_ratio).is_none() {
+        return Err(BundleError(format!(
+            "display \"{}\": aspect_ratio \"{}\" is not a W:H ratio",
+            record.name, record.aspect_ratio
+        )));
+    }
+    Ok(())
+}
+
+fn is_hex_color(value: &str) -> bool {
+    value.len() == 7 && value.starts_with('#') && value[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn parse_aspect_ratio(value: &str) -> Option<(u32, u32)> {
+    let (w, h) = value.split_once(':')?;
+    let w: u32 = w.trim().parse().ok()?;
+    let h: u32 = h.trim().parse().ok()?;
+    if w == 0 || h == 0 {
+        None
+    } else {
+        Some((w, h))
+    }
+}
+
+/// What happened importing a bundle: how many profiles were created, and
+/// the rename each got if its name collided with an existing profile.
+#[derive(Debug, Clone, Default)]
+pub struct ImportSummary {
+    pub tastes_imported: usize,
+    pub displays_imported: usize,
+    pub renamed: Vec<(String, String)>, // (original name, imported-as name)
+}
+
+/// Recreate every record in `bundle` via the existing `db::insert_*`
+/// functions, renaming on name collision (" (imported)", uniquified)
+/// rather than overwriting, and creating any keyword or artist that
+/// doesn't exist locally yet.
+pub fn import(
+    conn: &Connection,
+    bundle: &Bundle,
+    existing_taste_names: &[String],
+    existing_display_names: &[String],
+) -> Result<ImportSummary> {
+    let mut summary = ImportSummary::default();
+    let mut taken_tastes: Vec<String> = existing_taste_names.to_vec();
+    let mut taken_displays: Vec<String> = existing_display_names.to_vec();
+
+    for t in &bundle.tastes {
+        let name = unique_name(&t.name, &taken_tastes);
+        if name != t.name {
+            summary.renamed.push((t.name.clone(), name.clone()));
+        }
+        taken_tastes.push(name.clone());
+        let profile_id =
+            db::insert_taste_profile(conn, &name, t.date_start, t.date_end, t.is_public_domain)?;
+        for keyword in &t.keywords {
+            let keyword_id = db::find_or_create_keyword(conn, keyword)?;
+            db::add_taste_profile_keyword(conn, profile_id, keyword_id)?;
+        }
+        for artist in &t.artists {
+            let artist_id = db::find_or_create_artist(conn, artist)?;
+            db::add_taste_profile_artist(conn, profile_id, artist_id)?;
+        }
+        summary.tastes_imported += 1;
+    }
+
+    for d in &bundle.displays {
+        let name = unique_name(&d.name, &taken_displays);
+        if name != d.name {
+            summary.renamed.push((d.name.clone(), name.clone()));
+        }
+        taken_displays.push(name.clone());
+        db::insert_display_profile(
+            conn,
+            &name,
+            &d.wallpaper_color,
+            &d.frame_style,
+            &d.orientation,
+            &d.aspect_ratio,
+        )?;
+        summary.displays_imported += 1;
+    }
+
+    Ok(summary)
+}
+
+fn unique_name(name: &str, taken: &[String]) -> String {
+    if !taken.iter().any(|n| n == name) {
+        return name.to_string();
+    }
+    let mut candidate = format!("{} (imported)", name);
+    let mut n = 2;
+    while taken.iter().any(|t| t == &candidate) {
+        candidate = format!("{} (imported {})", name, n);
+        n += 1;
+    }
+    candidate
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+
+use anyhow::Result;
+
+use crate::bundle;
+use crate::db;
+
+/// A subcommand parsed from argv, letting `artgg` run headless (e.g. from
+/// cron) instead of always dropping into the interactive TUI.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Build {
+        taste: String,
+        display: String,
+        output: String,
+    },
+    ListTaste,
+    ListDisplay,
+    Prune,
+    Export(String),
+    Import(String),
+}
+
+/// Bad argv for a subcommand; printed to stderr as-is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliError(pub String);
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Parse argv (excluding the program name). `Ok(None)` means no subcommand
+/// was given, so the caller should fall through to launching the TUI.
+///
+/// Flagging for maintainer sign-off rather than silently diverging: the
+/// original request for this parser asked for it to be clap-based. This
+/// crate has no `Cargo.toml` anywhere in the tree (so no dependency graph
+/// to add clap to and nothing to `cargo build` against), which is why a
+/// hand-rolled parser was used instead — it covers the same subcommands
+/// and usage strings, but doesn't get clap's derive macros, help
+/// generation, or argument validation. Revisit this once the crate has a
+/// manifest.
+pub fn parse_args(args: &[String]) -> Result<Option<Command>, CliError> {
+    let mut args = args.iter();
+    let verb = match args.next() {
+        Some(v) => v.as_str(),
+        None => return Ok(None),
+    };
+    match verb {
+        "build" => {
+            let mut taste = None;
+            let mut display = None;
+            let mut output = None;
+            while let Some(flag) = args.next() {
+                let value = args
+                    .next()
+                    .ok_or_else(|| CliError(format!("{} expects a value", flag)))?
+                    .clone();
+                match flag.as_str() {
+                    "--taste" => taste = Some(value),
+                    "--display" => display = Some(value),
+                    "--output" => output = Some(value),
+                    other => return Err(CliError(format!("unknown flag: {}", other))),
+                }
+            }
+            match (taste, display, output) {
+                (Some(taste), Some(display), Some(output)) => Ok(Some(Command::Build {
+                    taste,
+                    display,
+                    output,
+                })),
+                _ => Err(CliError(
+                    "usage: artgg build --taste <name> --display <name> --output <dir>"
+                        .to_string(),
+                )),
+            }
+        }
+        "list" => match args.next().map(|s| s.as_str()) {
+            Some("taste") => Ok(Some(Command::ListTaste)),
+            Some("display") => Ok(Some(Command::ListDisplay)),
+            _ => Err(CliError("usage: artgg list taste|display".to_string())),
+        },
+        "prune" => Ok(Some(Command::Prune)),
+        "export" => match args.next() {
+            Some(file) => Ok(Some(Command::Export(file.clone()))),
+            None => Err(CliError("usage: artgg export <file>".to_string())),
+        },
+        "import" => match args.next() {
+            Some(file) => Ok(Some(Command::Import(file.clone()))),
+            None => Err(CliError("usage: artgg import <file>".to_string())),
+        },
+        other => Err(CliError(format!("unknown subcommand: {}", other))),
+    }
+}
+
+/// Run a parsed subcommand against the same SQLite-backed helpers the TUI
+/// uses, printing results to stdout instead of drawing a screen.
+pub fn run(command: Command) -> Result<()> {
+    let conn = db::open()?;
+    match command {
+        Command::ListTaste => {
+            for profile in db::load_taste_profiles(&conn)? {
+                println!("{}", profile.name);
+            }
+        }
+        Command::ListDisplay => {
+            for profile in db::load_display_profiles(&conn)? {
+                println!("{}", profile.name);
+            }
+        }
+        Command::Build {
+            taste,
+            display,
+            output,
+        } => {
+            let tastes = db::load_taste_profiles(&conn)?;
+            let displays = db::load_display_profiles(&conn)?;
+            let taste_profile = tastes
+                .iter()
+                .find(|p| p.name == taste)
+                .ok_or_else(|| anyhow::anyhow!("no taste profile named \"{}\"", taste))?;
+            let display_profile = displays
+                .iter()
+                .find(|p| p.name == display)
+                .ok_or_else(|| anyhow::anyhow!("no display profile named \"{}\"", display))?;
+            // Gallery generation itself isn't implemented yet anywhere in
+            // this codebase — the TUI's build wizard stops at the same
+            // point, reporting the resolved selection instead of writing
+            // wallpapers to `output`.
+            println!(
+                "would build \"{}\" x \"{}\" into {} (gallery generation not implemented yet)",
+                taste_profile.name, display_profile.name, output
+            );
+        }
+        Command::Prune => {
+            println!("prune is not implemented yet");
+        }
+        Command::Export(path) => {
+            let tastes = db::load_taste_profiles(&conn)?;
+            let displays = db::load_display_profiles(&conn)?;
+            let text = bundle::export(&tastes, &displays);
+            std::fs::write(&path, text)?;
+            println!(
+                "exported {} taste profile(s) and {} display profile(s) to {}",
+                tastes.len(),
+                displays.len(),
+                path
+            );
+        }
+        Command::Import(path) => {
+            let text = std::fs::read_to_string(&path)?;
+            let parsed = bundle::parse(&text).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+            let existing_taste_names: Vec<String> = db::load_taste_profiles(&conn)?
+                .into_iter()
+                .map(|p| p.name)
+                .collect();
+            let existing_display_names: Vec<String> = db::load_display_profiles(&conn)?
+                .into_iter()
+                .map(|p| p.name)
+                .collect();
+            let summary = bundle::import(
+                &conn,
+                &parsed,
+                &existing_taste_names,
+                &existing_display_names,
+            )?;
+            println!(
+                "imported {} taste profile(s) and {} display profile(s)",
+                summary.tastes_imported, summary.displays_imported
+            );
+            for (from, to) in &summary.renamed {
+                println!("  renamed on conflict: {} -> {}", from, to);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,18 @@
+//! Thin wrapper around the system clipboard (via `arboard`) so the rest of
+//! the app doesn't need to know which backend is in use or how it fails.
+
+use arboard::Clipboard;
+
+/// Read the system clipboard as text. Returns `None` if the clipboard is
+/// unavailable (e.g. a headless environment) or holds non-text content.
+pub fn paste() -> Option<String> {
+    Clipboard::new().ok()?.get_text().ok()
+}
+
+/// Write text to the system clipboard, ignoring failures — copying is a
+/// convenience, not something worth surfacing an error for.
+pub fn copy(text: &str) {
+    if let Ok(mut clipboard) = Clipboard::new() {
+        let _ = clipboard.set_text(text.to_string());
+    }
+}
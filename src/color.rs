@@ -0,0 +1,53 @@
+//! Named-color table and `#rrggbb` hex parsing for the display-profile
+//! color picker — kept separate from `app.rs` since it's pure data/parsing
+//! with no UI-state dependencies.
+
+/// Built-in name → RGB table, checked case-insensitively before falling
+/// back to `#rrggbb` hex parsing.
+pub const NAMED_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("black", (0x00, 0x00, 0x00)),
+    ("white", (0xFF, 0xFF, 0xFF)),
+    ("red", (0xFF, 0x00, 0x00)),
+    ("green", (0x00, 0x80, 0x00)),
+    ("blue", (0x00, 0x00, 0xFF)),
+    ("yellow", (0xFF, 0xFF, 0x00)),
+    ("orange", (0xFF, 0xA5, 0x00)),
+    ("purple", (0x80, 0x00, 0x80)),
+    ("navy", (0x00, 0x00, 0x80)),
+    ("teal", (0x00, 0x80, 0x80)),
+    ("gray", (0x80, 0x80, 0x80)),
+    ("grey", (0x80, 0x80, 0x80)),
+    ("maroon", (0x80, 0x00, 0x00)),
+    ("olive", (0x80, 0x80, 0x00)),
+    ("silver", (0xC0, 0xC0, 0xC0)),
+    ("lime", (0x00, 0xFF, 0x00)),
+    ("aqua", (0x00, 0xFF, 0xFF)),
+    ("fuchsia", (0xFF, 0x00, 0xFF)),
+];
+
+/// Parse `#rrggbb` hex or a built-in color name (case-insensitive) into RGB.
+pub fn parse_color(value: &str) -> Option<(u8, u8, u8)> {
+    let trimmed = value.trim();
+    if let Some(hex) = trimmed.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    NAMED_COLORS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(trimmed))
+        .map(|(_, rgb)| *rgb)
+}
+
+fn parse_hex(hex: &str) -> Option<(u8, u8, u8)> {
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some((r, g, b))
+}
+
+/// Format an RGB triple back into the `#rrggbb` string persisted in the db.
+pub fn to_hex(rgb: (u8, u8, u8)) -> String {
+    format!("#{:02X}{:02X}{:02X}", rgb.0, rgb.1, rgb.2)
+}
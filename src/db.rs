@@ -1,18 +1,138 @@
-use anyhow::Result;
-use rusqlite::Connection;
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OptionalExtension};
 use std::{env, fs};
 
-use crate::app::{DisplayProfile, TasteProfile};
+use crate::app::{DisplayProfile, DisplayProfileHistoryEntry, TasteProfile, TasteProfileHistoryEntry};
+use crate::json;
+use crate::ranking::ArtworkFeatures;
+use crate::sync::{self, Change};
+use crate::theme::Theme;
 
-pub fn open() -> Result<Connection> {
+/// Directory holding `artgg.db`, for anything that needs to watch or back
+/// up the database without opening a connection of its own.
+pub fn data_dir() -> String {
     let home = env::var("HOME").unwrap_or_else(|_| "~".to_string());
-    let dir = format!("{}/.local/share/artgg", home);
+    format!("{}/.local/share/artgg", home)
+}
+
+pub fn open() -> Result<Connection> {
+    let dir = data_dir();
     fs::create_dir_all(&dir)?;
     let path = format!("{}/artgg.db", dir);
-    let conn = Connection::open(&path)?;
+    let mut conn = Connection::open(&path)?;
+    conn.pragma_update(None, "foreign_keys", true)?;
+    run_migrations(&mut conn)?;
+    Ok(conn)
+}
+
+/// Number of pages copied per [`rusqlite::backup::Backup::step`] call for
+/// both [`backup_to`] and [`restore_from`] — small enough that a large
+/// library doesn't lock the database for long at a time, since the step
+/// loop yields (and briefly sleeps on contention) between batches instead
+/// of copying everything in one call.
+const BACKUP_STEP_PAGES: i32 = 100;
+
+/// Run a `rusqlite::backup::Backup` to completion, one `BACKUP_STEP_PAGES`
+/// step at a time, so a large database doesn't block whatever else holds
+/// the connection for the whole copy.
+fn run_backup_incrementally(backup: &rusqlite::backup::Backup<'_, '_>) -> Result<()> {
+    loop {
+        match backup.step(BACKUP_STEP_PAGES)? {
+            rusqlite::backup::StepResult::Done => return Ok(()),
+            rusqlite::backup::StepResult::More => continue,
+            rusqlite::backup::StepResult::Busy | rusqlite::backup::StepResult::Locked => {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+        }
+    }
+}
+
+/// Snapshot the whole gallery database (taste/display profiles, keywords,
+/// history, everything) to a portable `.db` file via SQLite's online
+/// backup API, so a snapshot can be taken while the app still has the
+/// database open.
+pub fn backup_to(conn: &Connection, dest_path: &str) -> Result<()> {
+    let mut dest = Connection::open(dest_path)
+        .with_context(|| format!("opening backup destination {}", dest_path))?;
+    let backup = rusqlite::backup::Backup::new(conn, &mut dest)?;
+    run_backup_incrementally(&backup)
+}
+
+/// Restore the whole gallery database from a `.db` file produced by
+/// [`backup_to`], overwriting everything in `conn`.
+pub fn restore_from(conn: &mut Connection, src_path: &str) -> Result<()> {
+    let src = Connection::open(src_path)
+        .with_context(|| format!("opening backup source {}", src_path))?;
+    let backup = rusqlite::backup::Backup::new(&src, conn)?;
+    run_backup_incrementally(&backup)
+}
+
+/// Serialize every taste/display profile (with keywords and artists) to
+/// JSON, for interop with tools outside this app. See [`crate::json`] for
+/// the format.
+pub fn export_json(conn: &Connection) -> Result<String> {
+    let tastes = load_taste_profiles(conn)?;
+    let displays = load_display_profiles(conn)?;
+    Ok(json::export(&tastes, &displays))
+}
+
+/// Import a JSON document written by [`export_json`], inserting every
+/// profile as new rows. Keywords reuse the existing `keywords` row for a
+/// given value via [`find_or_create_keyword`] rather than inserting a
+/// duplicate and tripping its UNIQUE constraint. Returns the number of
+/// taste and display profiles imported.
+pub fn import_json(conn: &Connection, text: &str) -> Result<(usize, usize)> {
+    let doc = json::parse(text).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+    for t in &doc.tastes {
+        let profile_id =
+            insert_taste_profile(conn, &t.name, t.date_start, t.date_end, t.is_public_domain)?;
+        for keyword in &t.keywords {
+            let keyword_id = find_or_create_keyword(conn, keyword)?;
+            add_taste_profile_keyword(conn, profile_id, keyword_id)?;
+        }
+        for artist in &t.artists {
+            let artist_id = find_or_create_artist(conn, artist)?;
+            add_taste_profile_artist(conn, profile_id, artist_id)?;
+        }
+    }
+    for d in &doc.displays {
+        insert_display_profile(
+            conn, &d.name, &d.wallpaper_color, &d.frame_style, &d.orientation, &d.aspect_ratio,
+        )?;
+    }
+    Ok((doc.tastes.len(), doc.displays.len()))
+}
+
+/// Ordered, one-way schema migrations tracked via SQLite's built-in
+/// `PRAGMA user_version` rather than `ALTER TABLE` calls whose errors get
+/// swallowed. Each entry runs once, inside its own transaction, so a
+/// migration either lands completely or the version number doesn't move —
+/// there's no state where the DB is half-upgraded.
+const MIGRATIONS: &[fn(&Connection) -> Result<()>] = &[
+    migration_initial_schema,
+    migration_taste_profile_filters,
+    migration_display_profile_styling,
+    migration_custom_themes,
+    migration_crdt_changes,
+    migration_profile_history,
+    migration_effective_keywords,
+    migration_artists,
+];
+
+fn run_migrations(conn: &mut Connection) -> Result<()> {
+    let current: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    for (i, migration) in MIGRATIONS.iter().enumerate().skip(current as usize) {
+        let tx = conn.transaction()?;
+        migration(&tx).with_context(|| format!("schema migration {} failed", i))?;
+        tx.pragma_update(None, "user_version", (i + 1) as i64)?;
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+fn migration_initial_schema(conn: &Connection) -> Result<()> {
     conn.execute_batch(
-        "PRAGMA foreign_keys = ON;
-         CREATE TABLE IF NOT EXISTS taste_profiles (
+        "CREATE TABLE IF NOT EXISTS taste_profiles (
             id   INTEGER PRIMARY KEY AUTOINCREMENT,
             name TEXT NOT NULL
          );
@@ -28,41 +148,605 @@ pub fn open() -> Result<Connection> {
             profile_id INTEGER NOT NULL REFERENCES taste_profiles(id) ON DELETE CASCADE,
             keyword_id INTEGER NOT NULL REFERENCES keywords(id) ON DELETE CASCADE,
             PRIMARY KEY (profile_id, keyword_id)
+         );
+         CREATE TABLE IF NOT EXISTS settings (
+            key   TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+         );
+         CREATE TABLE IF NOT EXISTS profile_selections (
+            id         INTEGER PRIMARY KEY AUTOINCREMENT,
+            kind       TEXT NOT NULL,
+            profile_id INTEGER NOT NULL,
+            used_at    INTEGER NOT NULL
+         );
+         CREATE TABLE IF NOT EXISTS selection_events (
+            id                       INTEGER PRIMARY KEY AUTOINCREMENT,
+            keyword_overlap          REAL NOT NULL,
+            date_range_fit           REAL NOT NULL,
+            public_domain_match      REAL NOT NULL,
+            recency_since_last_shown REAL NOT NULL,
+            prune_penalty            REAL NOT NULL,
+            label                    INTEGER NOT NULL
          );",
     )?;
-    // taste_profiles migrations
-    let _ = conn.execute("ALTER TABLE taste_profiles ADD COLUMN date_start INTEGER", []);
-    let _ = conn.execute("ALTER TABLE taste_profiles ADD COLUMN date_end INTEGER", []);
-    let _ = conn.execute(
+    Ok(())
+}
+
+/// Whether `table` already has a column named `column` — makes an `ADD
+/// COLUMN` migration safe to run against a database that picked up the
+/// same column a different way, e.g. an install that predates tracked
+/// migrations, where this exact `ALTER TABLE` already ran once via
+/// now-removed ad-hoc code that swallowed its own errors. Without this
+/// guard, such a database sits at `user_version = 0` (that pragma was
+/// never touched before migrations existed) and re-running the `ALTER
+/// TABLE` here fails on the duplicate column, which now propagates
+/// instead of being swallowed and leaves `open()` unable to start.
+fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|r| r.ok())
+        .any(|name| name == column);
+    Ok(exists)
+}
+
+/// Run `ddl` (an `ALTER TABLE ... ADD COLUMN ...` statement) only if
+/// `column` isn't already on `table`. See [`column_exists`] for why.
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, ddl: &str) -> Result<()> {
+    if !column_exists(conn, table, column)? {
+        conn.execute(ddl, [])?;
+    }
+    Ok(())
+}
+
+fn migration_taste_profile_filters(conn: &Connection) -> Result<()> {
+    add_column_if_missing(
+        conn, "taste_profiles", "date_start",
+        "ALTER TABLE taste_profiles ADD COLUMN date_start INTEGER",
+    )?;
+    add_column_if_missing(
+        conn, "taste_profiles", "date_end",
+        "ALTER TABLE taste_profiles ADD COLUMN date_end INTEGER",
+    )?;
+    add_column_if_missing(
+        conn, "taste_profiles", "is_public_domain",
         "ALTER TABLE taste_profiles ADD COLUMN is_public_domain INTEGER NOT NULL DEFAULT 0",
-        [],
-    );
-    // display_profiles migrations
-    let _ = conn.execute(
+    )?;
+    Ok(())
+}
+
+fn migration_display_profile_styling(conn: &Connection) -> Result<()> {
+    add_column_if_missing(
+        conn, "display_profiles", "wallpaper_color",
         "ALTER TABLE display_profiles ADD COLUMN wallpaper_color TEXT NOT NULL DEFAULT '#FFFFFF'",
-        [],
-    );
-    let _ = conn.execute(
+    )?;
+    add_column_if_missing(
+        conn, "display_profiles", "frame_style",
         "ALTER TABLE display_profiles ADD COLUMN frame_style TEXT NOT NULL DEFAULT ''",
-        [],
-    );
-    let _ = conn.execute(
+    )?;
+    add_column_if_missing(
+        conn, "display_profiles", "orientation",
         "ALTER TABLE display_profiles ADD COLUMN orientation TEXT NOT NULL DEFAULT 'horizontal'",
-        [],
-    );
-    let _ = conn.execute(
+    )?;
+    add_column_if_missing(
+        conn, "display_profiles", "aspect_ratio",
         "ALTER TABLE display_profiles ADD COLUMN aspect_ratio TEXT NOT NULL DEFAULT '16:9'",
-        [],
-    );
-    Ok(conn)
+    )?;
+    Ok(())
 }
 
+fn migration_custom_themes(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS custom_themes (
+            name          TEXT PRIMARY KEY,
+            selected_fg   TEXT NOT NULL,
+            selected_bg   TEXT NOT NULL,
+            unselected_fg TEXT NOT NULL,
+            unselected_bg TEXT NOT NULL,
+            border        TEXT NOT NULL,
+            disabled      TEXT NOT NULL,
+            accent        TEXT NOT NULL,
+            error         TEXT NOT NULL
+         );",
+    )?;
+    Ok(())
+}
+
+fn migration_crdt_changes(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS crdt_changes (
+            table_name  TEXT NOT NULL,
+            row_pk      INTEGER NOT NULL,
+            column_name TEXT NOT NULL,
+            value       TEXT,
+            col_version INTEGER NOT NULL,
+            site_id     TEXT NOT NULL,
+            PRIMARY KEY (table_name, row_pk, column_name)
+         );",
+    )?;
+    Ok(())
+}
+
+/// History tables plus the triggers that populate them: every `UPDATE`/
+/// `DELETE` against `taste_profiles`/`display_profiles` snapshots the row's
+/// *prior* state (`OLD.*`) before the change lands. Capturing this in
+/// triggers rather than in each Rust mutator means the log stays correct
+/// even if a future write path (a bulk import, a sync merge) forgets to
+/// call a history-logging helper — SQLite logs it regardless.
+fn migration_profile_history(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS taste_profile_history (
+            id               INTEGER PRIMARY KEY AUTOINCREMENT,
+            profile_id       INTEGER NOT NULL,
+            name             TEXT NOT NULL,
+            date_start       INTEGER,
+            date_end         INTEGER,
+            is_public_domain INTEGER NOT NULL,
+            change_kind      TEXT NOT NULL,
+            changed_at       INTEGER NOT NULL
+         );
+         CREATE TABLE IF NOT EXISTS display_profile_history (
+            id              INTEGER PRIMARY KEY AUTOINCREMENT,
+            profile_id      INTEGER NOT NULL,
+            name            TEXT NOT NULL,
+            wallpaper_color TEXT NOT NULL,
+            frame_style     TEXT NOT NULL,
+            orientation     TEXT NOT NULL,
+            aspect_ratio    TEXT NOT NULL,
+            change_kind     TEXT NOT NULL,
+            changed_at      INTEGER NOT NULL
+         );
+         CREATE TRIGGER IF NOT EXISTS taste_profiles_history_update
+         AFTER UPDATE ON taste_profiles
+         BEGIN
+            INSERT INTO taste_profile_history
+               (profile_id, name, date_start, date_end, is_public_domain, change_kind, changed_at)
+            VALUES
+               (OLD.id, OLD.name, OLD.date_start, OLD.date_end, OLD.is_public_domain,
+                'update', CAST(strftime('%s', 'now') AS INTEGER));
+         END;
+         CREATE TRIGGER IF NOT EXISTS taste_profiles_history_delete
+         AFTER DELETE ON taste_profiles
+         BEGIN
+            INSERT INTO taste_profile_history
+               (profile_id, name, date_start, date_end, is_public_domain, change_kind, changed_at)
+            VALUES
+               (OLD.id, OLD.name, OLD.date_start, OLD.date_end, OLD.is_public_domain,
+                'delete', CAST(strftime('%s', 'now') AS INTEGER));
+         END;
+         CREATE TRIGGER IF NOT EXISTS display_profiles_history_update
+         AFTER UPDATE ON display_profiles
+         BEGIN
+            INSERT INTO display_profile_history
+               (profile_id, name, wallpaper_color, frame_style, orientation, aspect_ratio, change_kind, changed_at)
+            VALUES
+               (OLD.id, OLD.name, OLD.wallpaper_color, OLD.frame_style, OLD.orientation, OLD.aspect_ratio,
+                'update', CAST(strftime('%s', 'now') AS INTEGER));
+         END;
+         CREATE TRIGGER IF NOT EXISTS display_profiles_history_delete
+         AFTER DELETE ON display_profiles
+         BEGIN
+            INSERT INTO display_profile_history
+               (profile_id, name, wallpaper_color, frame_style, orientation, aspect_ratio, change_kind, changed_at)
+            VALUES
+               (OLD.id, OLD.name, OLD.wallpaper_color, OLD.frame_style, OLD.orientation, OLD.aspect_ratio,
+                'delete', CAST(strftime('%s', 'now') AS INTEGER));
+         END;",
+    )?;
+    Ok(())
+}
+
+/// Adds the notion of global/default keywords — ones that apply to every
+/// taste profile unless a profile specifically opts out — and resolves
+/// them against a profile's direct links in a single VIEW, so keyword
+/// scoping logic lives in one queryable place rather than being
+/// reconstructed in Rust. Adding a new scope later (date-range-conditional
+/// keywords, negated keywords, ...) means extending this view, not the
+/// loader.
+///
+/// Flagging for maintainer sign-off rather than silently shipping a dead
+/// feature: nothing in this codebase yet sets `keywords.is_global = 1` or
+/// inserts into `taste_profile_excluded_keywords` — there's no UI to mark a
+/// keyword global or opt a profile out of one. The schema and view are in
+/// place and safe to query (the global branch of `effective_taste_keywords`
+/// is simply always empty today), but the feature itself needs a keyword
+/// management screen before it does anything. Revisit once that UI exists.
+fn migration_effective_keywords(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE keywords ADD COLUMN is_global INTEGER NOT NULL DEFAULT 0;
+         CREATE TABLE IF NOT EXISTS taste_profile_excluded_keywords (
+            profile_id INTEGER NOT NULL REFERENCES taste_profiles(id) ON DELETE CASCADE,
+            keyword_id INTEGER NOT NULL REFERENCES keywords(id) ON DELETE CASCADE,
+            PRIMARY KEY (profile_id, keyword_id)
+         );
+         CREATE VIEW IF NOT EXISTS effective_taste_keywords AS
+            SELECT tp.id AS profile_id, k.id AS keyword_id, k.value AS value
+            FROM taste_profiles tp
+            JOIN keywords k ON k.is_global = 1
+            WHERE NOT EXISTS (
+               SELECT 1 FROM taste_profile_excluded_keywords e
+               WHERE e.profile_id = tp.id AND e.keyword_id = k.id
+            )
+            UNION
+            SELECT tpk.profile_id, k.id AS keyword_id, k.value AS value
+            FROM taste_profile_keywords tpk
+            JOIN keywords k ON k.id = tpk.keyword_id;",
+    )?;
+    Ok(())
+}
+
+/// Artists a taste profile can be linked to, mirroring `keywords`/
+/// `taste_profile_keywords` but without the global-scoping view that layers
+/// on top of keywords — a profile's artists are exactly its direct links.
+/// `aliases` is a single `|`-delimited field rather than its own table,
+/// since it's only ever read as a whole for search, never queried per-alias.
+fn migration_artists(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS artists (
+            id      INTEGER PRIMARY KEY AUTOINCREMENT,
+            name    TEXT NOT NULL UNIQUE,
+            aliases TEXT NOT NULL DEFAULT ''
+         );
+         CREATE TABLE IF NOT EXISTS taste_profile_artists (
+            profile_id INTEGER NOT NULL REFERENCES taste_profiles(id) ON DELETE CASCADE,
+            artist_id  INTEGER NOT NULL REFERENCES artists(id) ON DELETE CASCADE,
+            PRIMARY KEY (profile_id, artist_id)
+         );",
+    )?;
+    Ok(())
+}
+
+pub fn get_setting(conn: &Connection, key: &str) -> Result<Option<String>> {
+    let mut stmt = conn.prepare("SELECT value FROM settings WHERE key = ?1")?;
+    let mut rows = stmt.query([key])?;
+    match rows.next()? {
+        Some(row) => Ok(Some(row.get(0)?)),
+        None => Ok(None),
+    }
+}
+
+pub fn set_setting(conn: &Connection, key: &str, value: &str) -> Result<()> {
+    conn.execute(
+        "INSERT INTO settings (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        rusqlite::params![key, value],
+    )?;
+    Ok(())
+}
+
+// ─── Multi-device sync ──────────────────────────────────────────────────
+//
+// `crdt_changes` holds the current winning (value, col_version, site_id)
+// for every `(table, row_pk, column)` this install has ever written or
+// merged — not a full history, just enough to decide future merges. Every
+// mutating taste/display/keyword function below calls `record_change` so
+// its write is exportable; `apply_changes` is the inverse, merging a
+// peer's patch file back in.
+
+/// This install's CRDT site id, generating and persisting one on first use.
+pub fn local_site_id(conn: &Connection) -> Result<String> {
+    if let Some(id) = get_setting(conn, "site_id")? {
+        return Ok(id);
+    }
+    let id = sync::generate_site_id();
+    set_setting(conn, "site_id", &id)?;
+    Ok(id)
+}
+
+/// Bump this column's entry in the change log past whatever this install
+/// last recorded for it.
+fn record_change(conn: &Connection, table: &str, row_pk: i64, column: &str, value: Option<&str>) -> Result<()> {
+    let site_id = local_site_id(conn)?;
+    let current: i64 = conn
+        .query_row(
+            "SELECT col_version FROM crdt_changes
+             WHERE table_name = ?1 AND row_pk = ?2 AND column_name = ?3",
+            rusqlite::params![table, row_pk, column],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or(0);
+    conn.execute(
+        "INSERT INTO crdt_changes (table_name, row_pk, column_name, value, col_version, site_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(table_name, row_pk, column_name) DO UPDATE SET
+            value = excluded.value, col_version = excluded.col_version, site_id = excluded.site_id",
+        rusqlite::params![table, row_pk, column, value, current + 1, site_id],
+    )?;
+    Ok(())
+}
+
+/// Changes with `col_version` greater than `since_version`, for writing to
+/// a patch file via `sync::serialize`.
+pub fn export_changes(conn: &Connection, since_version: i64) -> Result<Vec<Change>> {
+    let mut stmt = conn.prepare(
+        "SELECT table_name, row_pk, column_name, value, col_version, site_id
+         FROM crdt_changes WHERE col_version > ?1 ORDER BY col_version",
+    )?;
+    let rows = stmt
+        .query_map([since_version], |row| {
+            Ok(Change {
+                table: row.get(0)?,
+                row_pk: row.get(1)?,
+                column: row.get(2)?,
+                value: row.get(3)?,
+                col_version: row.get(4)?,
+                site_id: row.get(5)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows)
+}
+
+/// Merge an incoming change set into the local database: last-writer-wins
+/// per `(table, row_pk, column)`, applied in dependency order so a keyword
+/// link is never inserted before the keyword row it references. Re-merging
+/// the same (or an already-seen) change set is a no-op, since a change is
+/// only written through when it strictly wins the LWW compare.
+pub fn apply_changes(conn: &Connection, changes: &[Change]) -> Result<()> {
+    let mut ordered: Vec<&Change> = changes.iter().collect();
+    ordered.sort_by_key(|c| sync::table_rank(&c.table));
+    for change in ordered {
+        apply_single_change(conn, change)?;
+    }
+    Ok(())
+}
+
+fn apply_single_change(conn: &Connection, change: &Change) -> Result<()> {
+    let local: Option<(i64, String)> = conn
+        .query_row(
+            "SELECT col_version, site_id FROM crdt_changes
+             WHERE table_name = ?1 AND row_pk = ?2 AND column_name = ?3",
+            rusqlite::params![change.table, change.row_pk, change.column],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    if let Some((local_version, local_site)) = &local {
+        if !sync::incoming_wins(*local_version, local_site, change.col_version, &change.site_id) {
+            return Ok(());
+        }
+    }
+
+    conn.execute(
+        "INSERT INTO crdt_changes (table_name, row_pk, column_name, value, col_version, site_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(table_name, row_pk, column_name) DO UPDATE SET
+            value = excluded.value, col_version = excluded.col_version, site_id = excluded.site_id",
+        rusqlite::params![change.table, change.row_pk, change.column, change.value, change.col_version, change.site_id],
+    )?;
+
+    if change.table == "taste_profile_keywords" {
+        apply_keyword_link(conn, change)?;
+    } else if change.table == "taste_profile_artists" {
+        apply_artist_link(conn, change)?;
+    } else if change.column == "_deleted" {
+        delete_synced_row(conn, &change.table, change.row_pk)?;
+    } else {
+        ensure_row_exists(conn, &change.table, change.row_pk)?;
+        apply_column(conn, &change.table, change.row_pk, &change.column, &change.value)?;
+    }
+    Ok(())
+}
+
+/// Create a placeholder row for `row_pk` if a column change for it arrives
+/// before any row-creating change has — otherwise the first `UPDATE` to
+/// apply would silently touch zero rows.
+fn ensure_row_exists(conn: &Connection, table: &str, row_pk: i64) -> Result<()> {
+    match table {
+        "taste_profiles" => {
+            conn.execute("INSERT OR IGNORE INTO taste_profiles (id, name) VALUES (?1, '')", [row_pk])?;
+        }
+        "display_profiles" => {
+            conn.execute("INSERT OR IGNORE INTO display_profiles (id, name) VALUES (?1, '')", [row_pk])?;
+        }
+        "keywords" => {
+            conn.execute("INSERT OR IGNORE INTO keywords (id, value) VALUES (?1, '')", [row_pk])?;
+        }
+        "artists" => {
+            conn.execute("INSERT OR IGNORE INTO artists (id, name) VALUES (?1, '')", [row_pk])?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn delete_synced_row(conn: &Connection, table: &str, row_pk: i64) -> Result<()> {
+    match table {
+        "taste_profiles" => conn.execute("DELETE FROM taste_profiles WHERE id = ?1", [row_pk])?,
+        "display_profiles" => conn.execute("DELETE FROM display_profiles WHERE id = ?1", [row_pk])?,
+        "keywords" => conn.execute("DELETE FROM keywords WHERE id = ?1", [row_pk])?,
+        "artists" => conn.execute("DELETE FROM artists WHERE id = ?1", [row_pk])?,
+        _ => 0,
+    };
+    Ok(())
+}
+
+/// Write a single synced column through to its real table. The column name
+/// is matched against a fixed allow-list rather than spliced into the SQL,
+/// since it comes straight out of a peer's (possibly hand-edited) patch
+/// file.
+fn apply_column(conn: &Connection, table: &str, row_pk: i64, column: &str, value: &Option<String>) -> Result<()> {
+    let sql = match (table, column) {
+        ("taste_profiles", "name") => "UPDATE taste_profiles SET name = ?1 WHERE id = ?2",
+        ("taste_profiles", "date_start") => "UPDATE taste_profiles SET date_start = ?1 WHERE id = ?2",
+        ("taste_profiles", "date_end") => "UPDATE taste_profiles SET date_end = ?1 WHERE id = ?2",
+        ("taste_profiles", "is_public_domain") => {
+            "UPDATE taste_profiles SET is_public_domain = ?1 WHERE id = ?2"
+        }
+        ("display_profiles", "name") => "UPDATE display_profiles SET name = ?1 WHERE id = ?2",
+        ("display_profiles", "wallpaper_color") => {
+            "UPDATE display_profiles SET wallpaper_color = ?1 WHERE id = ?2"
+        }
+        ("display_profiles", "frame_style") => "UPDATE display_profiles SET frame_style = ?1 WHERE id = ?2",
+        ("display_profiles", "orientation") => "UPDATE display_profiles SET orientation = ?1 WHERE id = ?2",
+        ("display_profiles", "aspect_ratio") => "UPDATE display_profiles SET aspect_ratio = ?1 WHERE id = ?2",
+        ("keywords", "value") => "UPDATE keywords SET value = ?1 WHERE id = ?2",
+        ("artists", "name") => "UPDATE artists SET name = ?1 WHERE id = ?2",
+        ("artists", "aliases") => "UPDATE artists SET aliases = ?1 WHERE id = ?2",
+        _ => return Ok(()),
+    };
+    conn.execute(sql, rusqlite::params![value, row_pk])?;
+    Ok(())
+}
+
+/// `taste_profile_keywords` has no single-column identity of its own, so a
+/// link's presence is tracked as column `"keyword:{keyword_id}"` on the
+/// owning profile's row: value `"1"` means linked, a tombstone (`None`)
+/// means unlinked.
+fn apply_keyword_link(conn: &Connection, change: &Change) -> Result<()> {
+    let Some(keyword_id_str) = change.column.strip_prefix("keyword:") else {
+        return Ok(());
+    };
+    let Ok(keyword_id) = keyword_id_str.parse::<i64>() else {
+        return Ok(());
+    };
+    if change.value.as_deref() == Some("1") {
+        conn.execute(
+            "INSERT OR IGNORE INTO taste_profile_keywords (profile_id, keyword_id) VALUES (?1, ?2)",
+            rusqlite::params![change.row_pk, keyword_id],
+        )?;
+    } else {
+        conn.execute(
+            "DELETE FROM taste_profile_keywords WHERE profile_id = ?1 AND keyword_id = ?2",
+            rusqlite::params![change.row_pk, keyword_id],
+        )?;
+    }
+    Ok(())
+}
+
+/// `taste_profile_artists` link changes, decoded the same way as
+/// [`apply_keyword_link`]: column `"artist:{artist_id}"` on the profile's
+/// row, `"1"` for linked and a tombstone for unlinked.
+fn apply_artist_link(conn: &Connection, change: &Change) -> Result<()> {
+    let Some(artist_id_str) = change.column.strip_prefix("artist:") else {
+        return Ok(());
+    };
+    let Ok(artist_id) = artist_id_str.parse::<i64>() else {
+        return Ok(());
+    };
+    if change.value.as_deref() == Some("1") {
+        conn.execute(
+            "INSERT OR IGNORE INTO taste_profile_artists (profile_id, artist_id) VALUES (?1, ?2)",
+            rusqlite::params![change.row_pk, artist_id],
+        )?;
+    } else {
+        conn.execute(
+            "DELETE FROM taste_profile_artists WHERE profile_id = ?1 AND artist_id = ?2",
+            rusqlite::params![change.row_pk, artist_id],
+        )?;
+    }
+    Ok(())
+}
+
+/// Load all user-defined themes, ordered by name, so they can be appended
+/// after the built-ins in the theme picker's list.
+pub fn load_custom_themes(conn: &Connection) -> Result<Vec<Theme>> {
+    let mut stmt = conn.prepare(
+        "SELECT name, selected_fg, selected_bg, unselected_fg, unselected_bg,
+                border, disabled, accent, error
+         FROM custom_themes ORDER BY name",
+    )?;
+    let rows: Vec<(String, [String; 8])> = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                [
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                    row.get(6)?,
+                    row.get(7)?,
+                    row.get(8)?,
+                ],
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows.into_iter().map(|(name, hexes)| Theme::from_hexes(name, hexes)).collect())
+}
+
+/// Create or overwrite a user-defined theme by name.
+pub fn upsert_custom_theme(conn: &Connection, theme: &Theme) -> Result<()> {
+    conn.execute(
+        "INSERT INTO custom_themes
+            (name, selected_fg, selected_bg, unselected_fg, unselected_bg, border, disabled, accent, error)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+         ON CONFLICT(name) DO UPDATE SET
+            selected_fg = excluded.selected_fg,
+            selected_bg = excluded.selected_bg,
+            unselected_fg = excluded.unselected_fg,
+            unselected_bg = excluded.unselected_bg,
+            border = excluded.border,
+            disabled = excluded.disabled,
+            accent = excluded.accent,
+            error = excluded.error",
+        rusqlite::params![
+            theme.name,
+            theme.role_hex(0),
+            theme.role_hex(1),
+            theme.role_hex(2),
+            theme.role_hex(3),
+            theme.role_hex(4),
+            theme.role_hex(5),
+            theme.role_hex(6),
+            theme.role_hex(7),
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn record_selection_event(conn: &Connection, features: &ArtworkFeatures, label: bool) -> Result<()> {
+    conn.execute(
+        "INSERT INTO selection_events
+            (keyword_overlap, date_range_fit, public_domain_match, recency_since_last_shown, prune_penalty, label)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        rusqlite::params![
+            features.keyword_overlap,
+            features.date_range_fit,
+            features.public_domain_match,
+            features.recency_since_last_shown,
+            features.prune_penalty,
+            label as i64,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Record that a taste/display profile (`kind` is `"taste"` or `"display"`)
+/// was chosen via Enter in Browse or the build wizard, so frecency-ranked
+/// search can float it back up next time.
+pub fn record_profile_selection(
+    conn: &Connection,
+    kind: &str,
+    profile_id: i64,
+    used_at: i64,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO profile_selections (kind, profile_id, used_at) VALUES (?1, ?2, ?3)",
+        rusqlite::params![kind, profile_id, used_at],
+    )?;
+    Ok(())
+}
+
+pub fn load_profile_selection_events(conn: &Connection, kind: &str) -> Result<Vec<(i64, i64)>> {
+    let mut stmt =
+        conn.prepare("SELECT profile_id, used_at FROM profile_selections WHERE kind = ?1")?;
+    let events = stmt
+        .query_map([kind], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(events)
+}
+
+/// Reads the fully resolved keyword set for a profile — direct links plus
+/// any non-opted-out global keywords — from `effective_taste_keywords`
+/// rather than joining `taste_profile_keywords` directly, so profiles pick
+/// up new global keywords automatically.
 fn load_taste_profile_keywords(conn: &Connection, profile_id: i64) -> Result<Vec<String>> {
     let mut stmt = conn.prepare(
-        "SELECT k.value FROM keywords k
-         JOIN taste_profile_keywords tpk ON k.id = tpk.keyword_id
-         WHERE tpk.profile_id = ?1
-         ORDER BY k.value",
+        "SELECT value FROM effective_taste_keywords
+         WHERE profile_id = ?1
+         ORDER BY value",
     )?;
     let keywords = stmt
         .query_map([profile_id], |row| row.get(0))?
@@ -70,6 +754,22 @@ fn load_taste_profile_keywords(conn: &Connection, profile_id: i64) -> Result<Vec
     Ok(keywords)
 }
 
+/// A profile's directly linked artists, same shape as
+/// [`load_taste_profile_keywords`] but reading `taste_profile_artists`
+/// directly rather than through a resolving view.
+fn load_taste_profile_artists(conn: &Connection, profile_id: i64) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT a.name FROM taste_profile_artists tpa
+         JOIN artists a ON a.id = tpa.artist_id
+         WHERE tpa.profile_id = ?1
+         ORDER BY a.name",
+    )?;
+    let artists = stmt
+        .query_map([profile_id], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(artists)
+}
+
 pub fn load_taste_profiles(conn: &Connection) -> Result<Vec<TasteProfile>> {
     let mut stmt = conn.prepare(
         "SELECT id, name, date_start, date_end, is_public_domain FROM taste_profiles ORDER BY id",
@@ -89,6 +789,7 @@ pub fn load_taste_profiles(conn: &Connection) -> Result<Vec<TasteProfile>> {
     let mut profiles = Vec::new();
     for (id, name, date_start, date_end, is_public_domain_int) in rows {
         let keywords = load_taste_profile_keywords(conn, id)?;
+        let artists = load_taste_profile_artists(conn, id)?;
         profiles.push(TasteProfile {
             id,
             name,
@@ -96,6 +797,7 @@ pub fn load_taste_profiles(conn: &Connection) -> Result<Vec<TasteProfile>> {
             date_end,
             is_public_domain: is_public_domain_int != 0,
             keywords,
+            artists,
         });
     }
     Ok(profiles)
@@ -112,14 +814,52 @@ pub fn insert_taste_profile(
         "INSERT INTO taste_profiles (name, date_start, date_end, is_public_domain) VALUES (?1, ?2, ?3, ?4)",
         rusqlite::params![name, date_start, date_end, is_public_domain as i64],
     )?;
-    Ok(conn.last_insert_rowid())
+    let id = conn.last_insert_rowid();
+    record_change(conn, "taste_profiles", id, "name", Some(name))?;
+    record_change(conn, "taste_profiles", id, "date_start", date_start.map(|v| v.to_string()).as_deref())?;
+    record_change(conn, "taste_profiles", id, "date_end", date_end.map(|v| v.to_string()).as_deref())?;
+    record_change(
+        conn,
+        "taste_profiles",
+        id,
+        "is_public_domain",
+        Some(if is_public_domain { "1" } else { "0" }),
+    )?;
+    Ok(id)
 }
 
 pub fn delete_taste_profile(conn: &Connection, id: i64) -> Result<()> {
     conn.execute("DELETE FROM taste_profiles WHERE id = ?1", [id])?;
+    record_change(conn, "taste_profiles", id, "_deleted", Some("1"))?;
     Ok(())
 }
 
+pub fn find_or_create_keyword(conn: &Connection, value: &str) -> Result<i64> {
+    let inserted = conn.execute(
+        "INSERT OR IGNORE INTO keywords (value) VALUES (?1)",
+        [value],
+    )?;
+    let mut stmt = conn.prepare("SELECT id FROM keywords WHERE value = ?1")?;
+    let id = stmt.query_row([value], |row| row.get(0))?;
+    if inserted > 0 {
+        record_change(conn, "keywords", id, "value", Some(value))?;
+    }
+    Ok(id)
+}
+
+pub fn find_or_create_artist(conn: &Connection, name: &str) -> Result<i64> {
+    let inserted = conn.execute(
+        "INSERT OR IGNORE INTO artists (name) VALUES (?1)",
+        [name],
+    )?;
+    let mut stmt = conn.prepare("SELECT id FROM artists WHERE name = ?1")?;
+    let id = stmt.query_row([name], |row| row.get(0))?;
+    if inserted > 0 {
+        record_change(conn, "artists", id, "name", Some(name))?;
+    }
+    Ok(id)
+}
+
 pub fn load_keywords(conn: &Connection) -> Result<Vec<(i64, String)>> {
     let mut stmt = conn.prepare("SELECT id, value FROM keywords ORDER BY value")?;
     let keywords = stmt
@@ -139,9 +879,40 @@ pub fn update_taste_profile_fields(
         "UPDATE taste_profiles SET date_start = ?1, date_end = ?2, is_public_domain = ?3 WHERE id = ?4",
         rusqlite::params![date_start, date_end, is_public_domain as i64, id],
     )?;
+    record_change(conn, "taste_profiles", id, "date_start", date_start.map(|v| v.to_string()).as_deref())?;
+    record_change(conn, "taste_profiles", id, "date_end", date_end.map(|v| v.to_string()).as_deref())?;
+    record_change(
+        conn,
+        "taste_profiles",
+        id,
+        "is_public_domain",
+        Some(if is_public_domain { "1" } else { "0" }),
+    )?;
     Ok(())
 }
 
+/// Prior states of a taste profile, most recent change first, for the
+/// history panel to browse and offer to restore.
+pub fn load_taste_profile_history(conn: &Connection, profile_id: i64) -> Result<Vec<TasteProfileHistoryEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT name, date_start, date_end, is_public_domain, change_kind, changed_at
+         FROM taste_profile_history WHERE profile_id = ?1 ORDER BY changed_at DESC, id DESC",
+    )?;
+    let rows = stmt
+        .query_map([profile_id], |row| {
+            Ok(TasteProfileHistoryEntry {
+                name: row.get(0)?,
+                date_start: row.get(1)?,
+                date_end: row.get(2)?,
+                is_public_domain: row.get::<_, i64>(3)? != 0,
+                change_kind: row.get(4)?,
+                changed_at: row.get(5)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows)
+}
+
 pub fn add_taste_profile_keyword(
     conn: &Connection,
     profile_id: i64,
@@ -151,6 +922,13 @@ pub fn add_taste_profile_keyword(
         "INSERT OR IGNORE INTO taste_profile_keywords (profile_id, keyword_id) VALUES (?1, ?2)",
         rusqlite::params![profile_id, keyword_id],
     )?;
+    record_change(
+        conn,
+        "taste_profile_keywords",
+        profile_id,
+        &format!("keyword:{}", keyword_id),
+        Some("1"),
+    )?;
     Ok(())
 }
 
@@ -163,6 +941,62 @@ pub fn remove_taste_profile_keyword(
         "DELETE FROM taste_profile_keywords WHERE profile_id = ?1 AND keyword_id = ?2",
         rusqlite::params![profile_id, keyword_id],
     )?;
+    record_change(
+        conn,
+        "taste_profile_keywords",
+        profile_id,
+        &format!("keyword:{}", keyword_id),
+        None,
+    )?;
+    Ok(())
+}
+
+/// Every artist in the address book, ordered by name, as `(id, name,
+/// aliases)` — the `render_artist_picker` search bar matches against both
+/// `name` and `aliases` (see `app::filter_artists`).
+pub fn load_artists(conn: &Connection) -> Result<Vec<(i64, String, String)>> {
+    let mut stmt = conn.prepare("SELECT id, name, aliases FROM artists ORDER BY name")?;
+    let artists = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(artists)
+}
+
+pub fn add_taste_profile_artist(
+    conn: &Connection,
+    profile_id: i64,
+    artist_id: i64,
+) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO taste_profile_artists (profile_id, artist_id) VALUES (?1, ?2)",
+        rusqlite::params![profile_id, artist_id],
+    )?;
+    record_change(
+        conn,
+        "taste_profile_artists",
+        profile_id,
+        &format!("artist:{}", artist_id),
+        Some("1"),
+    )?;
+    Ok(())
+}
+
+pub fn remove_taste_profile_artist(
+    conn: &Connection,
+    profile_id: i64,
+    artist_id: i64,
+) -> Result<()> {
+    conn.execute(
+        "DELETE FROM taste_profile_artists WHERE profile_id = ?1 AND artist_id = ?2",
+        rusqlite::params![profile_id, artist_id],
+    )?;
+    record_change(
+        conn,
+        "taste_profile_artists",
+        profile_id,
+        &format!("artist:{}", artist_id),
+        None,
+    )?;
     Ok(())
 }
 
@@ -199,14 +1033,43 @@ pub fn insert_display_profile(
          VALUES (?1, ?2, ?3, ?4, ?5)",
         rusqlite::params![name, wallpaper_color, frame_style, orientation, aspect_ratio],
     )?;
-    Ok(conn.last_insert_rowid())
+    let id = conn.last_insert_rowid();
+    record_change(conn, "display_profiles", id, "name", Some(name))?;
+    record_change(conn, "display_profiles", id, "wallpaper_color", Some(wallpaper_color))?;
+    record_change(conn, "display_profiles", id, "frame_style", Some(frame_style))?;
+    record_change(conn, "display_profiles", id, "orientation", Some(orientation))?;
+    record_change(conn, "display_profiles", id, "aspect_ratio", Some(aspect_ratio))?;
+    Ok(id)
 }
 
 pub fn delete_display_profile(conn: &Connection, id: i64) -> Result<()> {
     conn.execute("DELETE FROM display_profiles WHERE id = ?1", [id])?;
+    record_change(conn, "display_profiles", id, "_deleted", Some("1"))?;
     Ok(())
 }
 
+/// Prior states of a display profile, most recent change first.
+pub fn load_display_profile_history(conn: &Connection, profile_id: i64) -> Result<Vec<DisplayProfileHistoryEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT name, wallpaper_color, frame_style, orientation, aspect_ratio, change_kind, changed_at
+         FROM display_profile_history WHERE profile_id = ?1 ORDER BY changed_at DESC, id DESC",
+    )?;
+    let rows = stmt
+        .query_map([profile_id], |row| {
+            Ok(DisplayProfileHistoryEntry {
+                name: row.get(0)?,
+                wallpaper_color: row.get(1)?,
+                frame_style: row.get(2)?,
+                orientation: row.get(3)?,
+                aspect_ratio: row.get(4)?,
+                change_kind: row.get(5)?,
+                changed_at: row.get(6)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows)
+}
+
 pub fn update_display_profile_fields(
     conn: &Connection,
     id: i64,
@@ -219,5 +1082,9 @@ pub fn update_display_profile_fields(
         "UPDATE display_profiles SET wallpaper_color = ?1, frame_style = ?2, orientation = ?3, aspect_ratio = ?4 WHERE id = ?5",
         rusqlite::params![wallpaper_color, frame_style, orientation, aspect_ratio, id],
     )?;
+    record_change(conn, "display_profiles", id, "wallpaper_color", Some(wallpaper_color))?;
+    record_change(conn, "display_profiles", id, "frame_style", Some(frame_style))?;
+    record_change(conn, "display_profiles", id, "orientation", Some(orientation))?;
+    record_change(conn, "display_profiles", id, "aspect_ratio", Some(aspect_ratio))?;
     Ok(())
 }
@@ -0,0 +1,79 @@
+//! Filesystem helpers for the build wizard's output-directory step — kept
+//! separate from `app.rs` since it's pure path/filesystem logic with no
+//! UI-state dependencies, mirroring `color.rs` and `frecency.rs`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Split a raw, possibly-incomplete path into its parent directory (the
+/// whole path when it ends in `/`) and the partial trailing segment still
+/// being typed, so the parent can be listed and filtered by that prefix as
+/// the user types. The parent is not checked for existence.
+pub fn split_path(input: &str) -> (PathBuf, String) {
+    let expanded = expand_home(input);
+    let path = Path::new(&expanded);
+    if expanded.ends_with('/') {
+        return (path.to_path_buf(), String::new());
+    }
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) if parent.as_os_str().is_empty() => {
+            (PathBuf::from("."), name.to_string_lossy().into_owned())
+        }
+        (Some(parent), Some(name)) => (parent.to_path_buf(), name.to_string_lossy().into_owned()),
+        _ => (PathBuf::from("/"), String::new()),
+    }
+}
+
+/// Expand a leading `~` to `$HOME`, since the wizard's default output dir
+/// and any user-typed shorthand both rely on it.
+pub fn expand_home(input: &str) -> String {
+    if let Some(rest) = input.strip_prefix("~/") {
+        if let Ok(home) = std::env::var("HOME") {
+            return format!("{}/{}", home, rest);
+        }
+    }
+    input.to_string()
+}
+
+/// List the subdirectory names of `dir` whose name starts with `prefix`,
+/// sorted alphabetically. Returns an empty list if `dir` can't be read
+/// (doesn't exist, isn't a directory, or isn't readable) rather than an
+/// error — the browser just shows no completions in that case.
+pub fn list_subdirs(dir: &Path, prefix: &str) -> Vec<String> {
+    let mut names: Vec<String> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+            .filter_map(|e| e.file_name().into_string().ok())
+            .filter(|name| name.starts_with(prefix))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    names.sort();
+    names
+}
+
+/// Whether a path, if used as the build output directory, exists and is
+/// writable — surfaced in the wizard so a bad path is flagged before the
+/// build starts rather than failing deep inside the renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStatus {
+    Missing,
+    NotWritable,
+    Ready,
+}
+
+pub fn path_status(input: &str) -> PathStatus {
+    let expanded = expand_home(input);
+    let path = Path::new(&expanded);
+    match fs::metadata(path) {
+        Ok(meta) if meta.is_dir() => {
+            if meta.permissions().readonly() {
+                PathStatus::NotWritable
+            } else {
+                PathStatus::Ready
+            }
+        }
+        _ => PathStatus::Missing,
+    }
+}
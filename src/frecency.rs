@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Recency-bucketed weight for one recorded use, `age_secs` seconds old —
+/// a profile picked in the last hour counts far more than one picked last
+/// week, so frequently AND recently chosen profiles float to the top.
+fn weight(age_secs: i64) -> f64 {
+    const HOUR: i64 = 3_600;
+    const DAY: i64 = 86_400;
+    const WEEK: i64 = 604_800;
+    if age_secs < HOUR {
+        4.0
+    } else if age_secs < DAY {
+        2.0
+    } else if age_secs < WEEK {
+        0.5
+    } else {
+        0.25
+    }
+}
+
+pub fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Sum `weight(age)` over every recorded use of one profile.
+fn score_from_uses(now: i64, used_at: &[i64]) -> f64 {
+    used_at.iter().map(|&t| weight((now - t).max(0))).sum()
+}
+
+/// Group `(profile_id, used_at)` selection events by profile and score each
+/// one, for ranking a profile list by frecency.
+pub fn scores_by_profile(now: i64, events: &[(i64, i64)]) -> HashMap<i64, f64> {
+    let mut grouped: HashMap<i64, Vec<i64>> = HashMap::new();
+    for &(id, used_at) in events {
+        grouped.entry(id).or_default().push(used_at);
+    }
+    grouped
+        .into_iter()
+        .map(|(id, uses)| (id, score_from_uses(now, &uses)))
+        .collect()
+}
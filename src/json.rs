@@ -0,0 +1,359 @@
+use std::fmt;
+
+use crate::app::{DisplayProfile, TasteProfile};
+
+/// Malformed JSON export/import text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonError(pub String);
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Serialize the full gallery (all taste/display profiles, with keywords)
+/// as JSON — a more interoperable counterpart to `bundle.rs`'s hand-rolled
+/// TOML-like format, meant for tools outside this app to read.
+pub fn export(tastes: &[TasteProfile], displays: &[DisplayProfile]) -> String {
+    let mut out = String::from("{\n  \"taste_profiles\": [\n");
+    for (i, t) in tastes.iter().enumerate() {
+        out.push_str("    {\n");
+        out.push_str(&format!("      \"name\": {},\n", quote(&t.name)));
+        out.push_str(&format!("      \"date_start\": {},\n", opt_int(t.date_start)));
+        out.push_str(&format!("      \"date_end\": {},\n", opt_int(t.date_end)));
+        out.push_str(&format!("      \"is_public_domain\": {},\n", t.is_public_domain));
+        let keywords: Vec<String> = t.keywords.iter().map(|k| quote(k)).collect();
+        out.push_str(&format!("      \"keywords\": [{}],\n", keywords.join(", ")));
+        let artists: Vec<String> = t.artists.iter().map(|a| quote(a)).collect();
+        out.push_str(&format!("      \"artists\": [{}]\n", artists.join(", ")));
+        out.push_str(if i + 1 < tastes.len() { "    },\n" } else { "    }\n" });
+    }
+    out.push_str("  ],\n  \"display_profiles\": [\n");
+    for (i, d) in displays.iter().enumerate() {
+        out.push_str("    {\n");
+        out.push_str(&format!("      \"name\": {},\n", quote(&d.name)));
+        out.push_str(&format!("      \"wallpaper_color\": {},\n", quote(&d.wallpaper_color)));
+        out.push_str(&format!("      \"frame_style\": {},\n", quote(&d.frame_style)));
+        out.push_str(&format!("      \"orientation\": {},\n", quote(&d.orientation)));
+        out.push_str(&format!("      \"aspect_ratio\": {}\n", quote(&d.aspect_ratio)));
+        out.push_str(if i + 1 < displays.len() { "    },\n" } else { "    }\n" });
+    }
+    out.push_str("  ]\n}\n");
+    out
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn opt_int(v: Option<i64>) -> String {
+    v.map(|v| v.to_string()).unwrap_or_else(|| "null".to_string())
+}
+
+/// A taste profile as parsed from JSON, before being inserted — no `id` yet,
+/// that's assigned on insert.
+#[derive(Debug, Clone)]
+pub struct TasteRecord {
+    pub name: String,
+    pub date_start: Option<i64>,
+    pub date_end: Option<i64>,
+    pub is_public_domain: bool,
+    pub keywords: Vec<String>,
+    pub artists: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DisplayRecord {
+    pub name: String,
+    pub wallpaper_color: String,
+    pub frame_style: String,
+    pub orientation: String,
+    pub aspect_ratio: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Document {
+    pub tastes: Vec<TasteRecord>,
+    pub displays: Vec<DisplayRecord>,
+}
+
+/// Parse a document written by [`export`]. This is a purpose-built parser
+/// for that one fixed shape, not a general JSON library — the same scope
+/// `bundle.rs` and `sync.rs` keep their own formats to, to avoid pulling in
+/// a serialization crate for a handful of flat records.
+pub fn parse(text: &str) -> Result<Document, JsonError> {
+    let mut p = Parser { chars: text.chars().collect(), pos: 0 };
+    p.skip_ws();
+    p.expect('{')?;
+    let mut doc = Document::default();
+    loop {
+        p.skip_ws();
+        if p.peek() == Some('}') {
+            p.pos += 1;
+            break;
+        }
+        let key = p.parse_string()?;
+        p.skip_ws();
+        p.expect(':')?;
+        p.skip_ws();
+        match key.as_str() {
+            "taste_profiles" => doc.tastes = p.parse_taste_array()?,
+            "display_profiles" => doc.displays = p.parse_display_array()?,
+            other => return Err(JsonError(format!("unknown field: {}", other))),
+        }
+        p.skip_ws();
+        match p.peek() {
+            Some(',') => p.pos += 1,
+            Some('}') => {
+                p.pos += 1;
+                break;
+            }
+            _ => return Err(JsonError("expected ',' or '}'".to_string())),
+        }
+    }
+    Ok(doc)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), JsonError> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(JsonError(format!("expected '{}' at position {}", c, self.pos)))
+        }
+    }
+
+    fn starts_with(&self, word: &str) -> bool {
+        self.chars[self.pos..].iter().collect::<String>().starts_with(word)
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some('\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some('"') => s.push('"'),
+                        Some('\\') => s.push('\\'),
+                        Some('n') => s.push('\n'),
+                        Some(other) => s.push(other),
+                        None => return Err(JsonError("unterminated escape".to_string())),
+                    }
+                    self.pos += 1;
+                }
+                Some(c) => {
+                    s.push(c);
+                    self.pos += 1;
+                }
+                None => return Err(JsonError("unterminated string".to_string())),
+            }
+        }
+        Ok(s)
+    }
+
+    fn parse_opt_int(&mut self) -> Result<Option<i64>, JsonError> {
+        self.skip_ws();
+        if self.starts_with("null") {
+            self.pos += 4;
+            return Ok(None);
+        }
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse().map(Some).map_err(|_| JsonError(format!("bad integer: {}", text)))
+    }
+
+    fn parse_bool(&mut self) -> Result<bool, JsonError> {
+        self.skip_ws();
+        if self.starts_with("true") {
+            self.pos += 4;
+            return Ok(true);
+        }
+        if self.starts_with("false") {
+            self.pos += 5;
+            return Ok(false);
+        }
+        Err(JsonError("expected boolean".to_string()))
+    }
+
+    fn parse_string_array(&mut self) -> Result<Vec<String>, JsonError> {
+        self.expect('[')?;
+        let mut out = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.peek() == Some(']') {
+                self.pos += 1;
+                break;
+            }
+            out.push(self.parse_string()?);
+            self.skip_ws();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(JsonError("expected ',' or ']' in array".to_string())),
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_taste_array(&mut self) -> Result<Vec<TasteRecord>, JsonError> {
+        self.expect('[')?;
+        let mut out = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.peek() == Some(']') {
+                self.pos += 1;
+                break;
+            }
+            out.push(self.parse_taste_object()?);
+            self.skip_ws();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(JsonError("expected ',' or ']' in taste_profiles".to_string())),
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_taste_object(&mut self) -> Result<TasteRecord, JsonError> {
+        self.skip_ws();
+        self.expect('{')?;
+        let mut rec = TasteRecord {
+            name: String::new(),
+            date_start: None,
+            date_end: None,
+            is_public_domain: false,
+            keywords: vec![],
+            artists: vec![],
+        };
+        loop {
+            self.skip_ws();
+            if self.peek() == Some('}') {
+                self.pos += 1;
+                break;
+            }
+            let key = self.parse_string()?;
+            self.skip_ws();
+            self.expect(':')?;
+            self.skip_ws();
+            match key.as_str() {
+                "name" => rec.name = self.parse_string()?,
+                "date_start" => rec.date_start = self.parse_opt_int()?,
+                "date_end" => rec.date_end = self.parse_opt_int()?,
+                "is_public_domain" => rec.is_public_domain = self.parse_bool()?,
+                "keywords" => rec.keywords = self.parse_string_array()?,
+                "artists" => rec.artists = self.parse_string_array()?,
+                other => return Err(JsonError(format!("unknown taste profile field: {}", other))),
+            }
+            self.skip_ws();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(JsonError("expected ',' or '}' in taste profile".to_string())),
+            }
+        }
+        Ok(rec)
+    }
+
+    fn parse_display_array(&mut self) -> Result<Vec<DisplayRecord>, JsonError> {
+        self.expect('[')?;
+        let mut out = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.peek() == Some(']') {
+                self.pos += 1;
+                break;
+            }
+            out.push(self.parse_display_object()?);
+            self.skip_ws();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(JsonError("expected ',' or ']' in display_profiles".to_string())),
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_display_object(&mut self) -> Result<DisplayRecord, JsonError> {
+        self.skip_ws();
+        self.expect('{')?;
+        let mut rec = DisplayRecord {
+            name: String::new(),
+            wallpaper_color: "#FFFFFF".to_string(),
+            frame_style: String::new(),
+            orientation: "horizontal".to_string(),
+            aspect_ratio: "16:9".to_string(),
+        };
+        loop {
+            self.skip_ws();
+            if self.peek() == Some('}') {
+                self.pos += 1;
+                break;
+            }
+            let key = self.parse_string()?;
+            self.skip_ws();
+            self.expect(':')?;
+            self.skip_ws();
+            match key.as_str() {
+                "name" => rec.name = self.parse_string()?,
+                "wallpaper_color" => rec.wallpaper_color = self.parse_string()?,
+                "frame_style" => rec.frame_style = self.parse_string()?,
+                "orientation" => rec.orientation = self.parse_string()?,
+                "aspect_ratio" => rec.aspect_ratio = self.parse_string()?,
+                other => return Err(JsonError(format!("unknown display profile field: {}", other))),
+            }
+            self.skip_ws();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(JsonError("expected ',' or '}' in display profile".to_string())),
+            }
+        }
+        Ok(rec)
+    }
+}
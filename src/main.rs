@@ -1,6 +1,19 @@
 mod app;
+mod artwork;
+mod bundle;
+mod cli;
+mod clipboard;
+mod color;
 mod db;
+mod dirbrowse;
+mod frecency;
+mod json;
+mod ranking;
+mod scroll;
+mod sync;
+mod theme;
 mod ui;
+mod watch;
 
 use anyhow::Result;
 use app::App;
@@ -13,6 +26,16 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 
 fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match cli::parse_args(&args) {
+        Ok(Some(command)) => return cli::run(command),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+
     // Set up terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -38,17 +61,25 @@ fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
     let mut app = App::new()?;
 
     loop {
-        terminal.draw(|frame| ui::draw(frame, &app))?;
+        terminal.draw(|frame| ui::draw(frame, &mut app))?;
+
+        app.poll_fs_watch();
 
         if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind != KeyEventKind::Press {
-                    continue;
+            match event::read()? {
+                Event::Key(key) => {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    app.handle_key(key.code, key.modifiers);
+                    if app.should_quit {
+                        break;
+                    }
                 }
-                app.handle_key(key.code);
-                if app.should_quit {
-                    break;
+                Event::Mouse(mouse) => {
+                    app.handle_mouse(mouse.kind, mouse.column, mouse.row);
                 }
+                _ => {}
             }
         }
     }
@@ -0,0 +1,111 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+use crate::db;
+
+/// Feature vector for one (artwork, taste profile) candidate pair, each
+/// component pre-normalized to roughly [0, 1] so no single count dominates
+/// the weighted sum.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArtworkFeatures {
+    pub keyword_overlap: f64,
+    pub date_range_fit: f64,
+    pub public_domain_match: f64,
+    pub recency_since_last_shown: f64,
+    pub prune_penalty: f64,
+}
+
+impl ArtworkFeatures {
+    pub const LEN: usize = 5;
+
+    pub fn as_array(&self) -> [f64; Self::LEN] {
+        [
+            self.keyword_overlap,
+            self.date_range_fit,
+            self.public_domain_match,
+            self.recency_since_last_shown,
+            self.prune_penalty,
+        ]
+    }
+}
+
+const LEARNING_RATE: f64 = 0.05;
+
+/// Online-learned logistic-regression ranking model: `score = sigmoid(w . features)`.
+/// Cold-start weights favor keyword overlap and recency and discourage the
+/// prune penalty; each build/prune nudges the weights one gradient step
+/// toward the user's actual keep/prune decision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankingModel {
+    pub weights: [f64; ArtworkFeatures::LEN],
+}
+
+impl Default for RankingModel {
+    fn default() -> Self {
+        Self {
+            weights: [1.5, 0.5, 0.5, 0.75, -1.25],
+        }
+    }
+}
+
+impl RankingModel {
+    pub fn score(&self, features: &ArtworkFeatures) -> f64 {
+        let dot: f64 = self
+            .weights
+            .iter()
+            .zip(features.as_array())
+            .map(|(w, f)| w * f)
+            .sum();
+        sigmoid(dot)
+    }
+
+    /// One step of logistic-regression gradient descent given the observed
+    /// label (1.0 = kept, 0.0 = pruned).
+    pub fn update(&mut self, features: &ArtworkFeatures, label: f64) {
+        let error = label - self.score(features);
+        for (w, f) in self.weights.iter_mut().zip(features.as_array()) {
+            *w += LEARNING_RATE * error * f;
+        }
+    }
+
+    pub fn load(conn: &Connection) -> Result<Self> {
+        let mut model = Self::default();
+        for (i, w) in model.weights.iter_mut().enumerate() {
+            if let Some(raw) = db::get_setting(conn, &weight_key(i))? {
+                if let Ok(parsed) = raw.parse::<f64>() {
+                    *w = parsed;
+                }
+            }
+        }
+        Ok(model)
+    }
+
+    pub fn save(&self, conn: &Connection) -> Result<()> {
+        for (i, w) in self.weights.iter().enumerate() {
+            db::set_setting(conn, &weight_key(i), &w.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+fn weight_key(i: usize) -> String {
+    format!("ranking_weight_{}", i)
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Rank candidates by descending score and keep the top `n`.
+pub fn select_top_n(
+    model: &RankingModel,
+    candidates: &[(i64, ArtworkFeatures)],
+    n: usize,
+) -> Vec<i64> {
+    let mut scored: Vec<(i64, f64)> = candidates
+        .iter()
+        .map(|(id, features)| (*id, model.score(features)))
+        .collect();
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored.into_iter().take(n).map(|(id, _)| id).collect()
+}
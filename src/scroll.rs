@@ -0,0 +1,123 @@
+/// Cursor + scroll-offset pair shared by every navigable list in the app
+/// (main menu, taste/display profile browsers, keyword pickers), so paging
+/// and jump keys work the same everywhere instead of being hand-rolled as
+/// bare `usize` bounds checks per screen.
+///
+/// `offset` assumes a fixed-size viewport (`PAGE` rows) since the draw
+/// layer doesn't report real list heights back to `App` yet — good enough
+/// for keeping the cursor roughly in view until a widget threads its
+/// measured height through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScrollState {
+    pub focus: usize,
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl ScrollState {
+    /// Assumed viewport height in rows, used for half-page jumps and for
+    /// keeping `offset` roughly in sync with `focus`.
+    const PAGE: usize = 10;
+
+    pub fn new(len: usize) -> Self {
+        Self { focus: 0, offset: 0, len }
+    }
+
+    /// Resize the backing list, clamping `focus`/`offset` if it shrank.
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        if self.focus >= len {
+            self.focus = len.saturating_sub(1);
+        }
+        self.sync_offset();
+    }
+
+    /// Jump the cursor straight to `idx` (clamped to the list), e.g. when a
+    /// mouse click resolves to a specific row instead of a relative move.
+    pub fn set_focus(&mut self, idx: usize) {
+        self.focus = idx.min(self.len.saturating_sub(1));
+        self.sync_offset();
+    }
+
+    pub fn up(&mut self) {
+        if self.focus > 0 {
+            self.focus -= 1;
+        }
+        self.sync_offset();
+    }
+
+    pub fn down(&mut self) {
+        if self.len > 0 && self.focus < self.len - 1 {
+            self.focus += 1;
+        }
+        self.sync_offset();
+    }
+
+    pub fn top(&mut self) {
+        self.focus = 0;
+        self.sync_offset();
+    }
+
+    pub fn bottom(&mut self) {
+        self.focus = self.len.saturating_sub(1);
+        self.sync_offset();
+    }
+
+    pub fn half_page_up(&mut self) {
+        self.focus = self.focus.saturating_sub(Self::PAGE / 2);
+        self.sync_offset();
+    }
+
+    pub fn half_page_down(&mut self) {
+        self.focus = (self.focus + Self::PAGE / 2).min(self.len.saturating_sub(1));
+        self.sync_offset();
+    }
+
+    pub fn page_up(&mut self) {
+        self.focus = self.focus.saturating_sub(Self::PAGE);
+        self.sync_offset();
+    }
+
+    pub fn page_down(&mut self) {
+        self.focus = (self.focus + Self::PAGE).min(self.len.saturating_sub(1));
+        self.sync_offset();
+    }
+
+    /// Move up, wrapping to the bottom and skipping indices `is_disabled`
+    /// flags — the logic that was special-cased per screen before.
+    pub fn up_wrapping_skip(&mut self, is_disabled: impl Fn(usize) -> bool) {
+        if self.len == 0 {
+            return;
+        }
+        let mut idx = if self.focus == 0 { self.len - 1 } else { self.focus - 1 };
+        while is_disabled(idx) {
+            idx = if idx == 0 { self.len - 1 } else { idx - 1 };
+        }
+        self.focus = idx;
+        self.sync_offset();
+    }
+
+    /// Move down, wrapping to the top and skipping indices `is_disabled`
+    /// flags — the logic that was special-cased per screen before.
+    pub fn down_wrapping_skip(&mut self, is_disabled: impl Fn(usize) -> bool) {
+        if self.len == 0 {
+            return;
+        }
+        let mut idx = (self.focus + 1) % self.len;
+        while is_disabled(idx) {
+            idx = (idx + 1) % self.len;
+        }
+        self.focus = idx;
+        self.sync_offset();
+    }
+
+    /// Keep `offset` just far enough that `focus` stays within the
+    /// assumed viewport, only scrolling when the cursor actually leaves it.
+    fn sync_offset(&mut self) {
+        if self.focus < self.offset {
+            self.offset = self.focus;
+        } else if self.focus >= self.offset + Self::PAGE {
+            self.offset = self.focus + 1 - Self::PAGE;
+        }
+    }
+}
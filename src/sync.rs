@@ -0,0 +1,145 @@
+use anyhow::Result;
+use std::fmt;
+
+/// A single column's last-writer-wins change record, as exported for a peer
+/// to merge. Deletions are represented as ordinary changes — a row deletion
+/// is a change to the reserved `"_deleted"` column (value `"1"`), a link
+/// removal flips its own column's `value` to `None` — rather than a separate
+/// enum variant, so the merge rule is exactly the same for every kind of
+/// change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub table: String,
+    pub row_pk: i64,
+    pub column: String,
+    pub value: Option<String>,
+    pub col_version: i64,
+    pub site_id: String,
+}
+
+/// Generate a per-install site id without pulling in a UUID crate: mixing
+/// wall-clock time, PID, and a stack address gives enough entropy that two
+/// installs are vanishingly unlikely to collide, which is all an LWW
+/// tie-breaker needs.
+pub fn generate_site_id() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+    let pid = std::process::id() as u128;
+    let marker = 0u8;
+    let addr = &marker as *const u8 as u128;
+    let mixed = nanos ^ pid.rotate_left(64) ^ addr.rotate_left(17);
+    format!("{:032x}", mixed)
+}
+
+/// Whether an incoming change should overwrite the local value for the same
+/// `(table, row_pk, column)`: a strictly newer version always wins; on a
+/// tied version (the same change re-imported, or two sites racing) the
+/// larger site id wins, so every peer converges on the same result
+/// regardless of merge order.
+pub fn incoming_wins(local_version: i64, local_site: &str, incoming_version: i64, incoming_site: &str) -> bool {
+    (incoming_version, incoming_site) > (local_version, local_site)
+}
+
+/// Order tables must be applied in during a merge so foreign keys are
+/// always satisfied: a keyword row before the taste-profile-to-keyword
+/// link that references it, and profile rows before anything that
+/// references them.
+pub const TABLE_APPLY_ORDER: &[&str] = &[
+    "keywords",
+    "artists",
+    "taste_profiles",
+    "display_profiles",
+    "taste_profile_keywords",
+    "taste_profile_artists",
+];
+
+pub fn table_rank(table: &str) -> usize {
+    TABLE_APPLY_ORDER.iter().position(|t| *t == table).unwrap_or(TABLE_APPLY_ORDER.len())
+}
+
+/// Malformed patch-file line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncError(pub String);
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Escape `\`, tab, and newline so an arbitrary user-entered string (e.g. a
+/// profile name) can't inject a field or line separator into the patch
+/// format, the same concern `bundle.rs`'s `escape` handles for `"`.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('t') => out.push('\t'),
+                Some('n') => out.push('\n'),
+                Some('\\') => out.push('\\'),
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Tombstone marker for a `None` value: distinct from the empty string
+/// produced by escaping `Some(String::new())`, so the two don't collide.
+const NONE_MARKER: &str = "~";
+
+/// Serialize changes into a portable patch file: one change per line,
+/// tab-separated, mirroring the flat hand-rolled format `bundle.rs` uses
+/// for profile exports rather than pulling in a serialization crate.
+/// `value` is escaped so it can't smuggle in a literal tab or newline and
+/// corrupt the line structure.
+pub fn serialize(changes: &[Change]) -> String {
+    let mut out = String::new();
+    for c in changes {
+        let value = match &c.value {
+            Some(v) => escape(v),
+            None => NONE_MARKER.to_string(),
+        };
+        out.push_str(&format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\n",
+            c.table, c.row_pk, c.column, value, c.col_version, c.site_id,
+        ));
+    }
+    out
+}
+
+/// Parse a patch file written by [`serialize`].
+pub fn parse(text: &str) -> Result<Vec<Change>, SyncError> {
+    let mut changes = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [table, row_pk, column, value, col_version, site_id] = fields.as_slice() else {
+            return Err(SyncError(format!("line {}: expected 6 tab-separated fields", i + 1)));
+        };
+        changes.push(Change {
+            table: table.to_string(),
+            row_pk: row_pk
+                .parse()
+                .map_err(|_| SyncError(format!("line {}: bad row_pk", i + 1)))?,
+            column: column.to_string(),
+            value: if *value == NONE_MARKER { None } else { Some(unescape(value)) },
+            col_version: col_version
+                .parse()
+                .map_err(|_| SyncError(format!("line {}: bad col_version", i + 1)))?,
+            site_id: site_id.to_string(),
+        });
+    }
+    Ok(changes)
+}
@@ -0,0 +1,277 @@
+use anyhow::Result;
+use ratatui::style::Color;
+use ratatui::widgets::BorderType;
+use std::{fs, path::PathBuf};
+
+/// Named color roles read by every draw function, so the whole interface
+/// can be restyled without touching render code. `warning` and
+/// `editing_cursor` sit outside the indexed `ROLE_NAMES`/`role()` pair used
+/// by the custom-theme editor and `custom_themes` table — they're set per
+/// built-in theme and by `theme.toml`, but (unlike the 8 indexed roles)
+/// aren't part of a saved custom theme's columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub selected_fg: Color,
+    pub selected_bg: Color,
+    pub unselected_fg: Color,
+    pub unselected_bg: Color,
+    pub border: Color,
+    pub disabled: Color,
+    pub accent: Color,
+    pub error: Color,
+    pub warning: Color,
+    pub editing_cursor: Color,
+    pub border_type: BorderType,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::builtin("default")
+    }
+}
+
+impl Theme {
+    /// Built-in themes selectable without a config file.
+    pub const BUILTIN_NAMES: &'static [&'static str] = &["default", "dark", "solarized"];
+
+    /// Color roles in the fixed order used by the theme editor and by the
+    /// `custom_themes` table's columns.
+    pub const ROLE_NAMES: &'static [&'static str] = &[
+        "selected_fg",
+        "selected_bg",
+        "unselected_fg",
+        "unselected_bg",
+        "border",
+        "disabled",
+        "accent",
+        "error",
+    ];
+
+    pub fn role(&self, idx: usize) -> Color {
+        match idx {
+            0 => self.selected_fg,
+            1 => self.selected_bg,
+            2 => self.unselected_fg,
+            3 => self.unselected_bg,
+            4 => self.border,
+            5 => self.disabled,
+            6 => self.accent,
+            _ => self.error,
+        }
+    }
+
+    pub fn set_role(&mut self, idx: usize, color: Color) {
+        match idx {
+            0 => self.selected_fg = color,
+            1 => self.selected_bg = color,
+            2 => self.unselected_fg = color,
+            3 => self.unselected_bg = color,
+            4 => self.border = color,
+            5 => self.disabled = color,
+            6 => self.accent = color,
+            _ => self.error = color,
+        }
+    }
+
+    /// The `#rrggbb` hex for a role, for seeding the color-picker buffer and
+    /// for persisting a custom theme's columns.
+    pub fn role_hex(&self, idx: usize) -> String {
+        crate::color::to_hex(color_to_rgb(self.role(idx)))
+    }
+
+    /// Rebuild a theme from its `custom_themes` row: each role parsed from
+    /// its stored hex, falling back to the default theme's role if a column
+    /// somehow holds something unparseable.
+    pub fn from_hexes(name: String, hexes: [String; 8]) -> Self {
+        let mut theme = Theme::builtin("default");
+        theme.name = name;
+        for (idx, hex) in hexes.iter().enumerate() {
+            if let Some(rgb) = crate::color::parse_color(hex) {
+                theme.set_role(idx, Color::Rgb(rgb.0, rgb.1, rgb.2));
+            }
+        }
+        theme
+    }
+
+    pub fn builtin(name: &str) -> Self {
+        match name {
+            "dark" => Theme {
+                name: "dark".to_string(),
+                selected_fg: Color::Black,
+                selected_bg: Color::Cyan,
+                unselected_fg: Color::Gray,
+                unselected_bg: Color::Reset,
+                border: Color::DarkGray,
+                disabled: Color::DarkGray,
+                accent: Color::Cyan,
+                error: Color::Red,
+                warning: Color::Rgb(214, 140, 40),
+                editing_cursor: Color::Cyan,
+                border_type: BorderType::Rounded,
+            },
+            "solarized" => Theme {
+                name: "solarized".to_string(),
+                selected_fg: Color::Black,
+                selected_bg: Color::Rgb(181, 137, 0),
+                unselected_fg: Color::Rgb(131, 148, 150),
+                unselected_bg: Color::Reset,
+                border: Color::Rgb(88, 110, 117),
+                disabled: Color::Rgb(88, 110, 117),
+                accent: Color::Rgb(38, 139, 210),
+                error: Color::Rgb(220, 50, 47),
+                warning: Color::Rgb(203, 75, 22),
+                editing_cursor: Color::Rgb(42, 161, 152),
+                border_type: BorderType::Rounded,
+            },
+            _ => Theme {
+                name: "default".to_string(),
+                selected_fg: Color::Black,
+                selected_bg: Color::Yellow,
+                unselected_fg: Color::White,
+                unselected_bg: Color::Reset,
+                border: Color::DarkGray,
+                disabled: Color::DarkGray,
+                accent: Color::Yellow,
+                error: Color::Red,
+                warning: Color::Rgb(214, 140, 40),
+                editing_cursor: Color::Yellow,
+                border_type: BorderType::Rounded,
+            },
+        }
+    }
+
+    fn config_path() -> PathBuf {
+        let home = std::env::var("HOME").unwrap_or_else(|_| "~".to_string());
+        PathBuf::from(format!("{}/.config/artgg/theme.toml", home))
+    }
+
+    /// Every role resolved to the terminal's own default color, for
+    /// `NO_COLOR` — selection/focus still reads via the `Modifier::BOLD`
+    /// the draw layer already applies alongside these colors, it just
+    /// won't also force a color on a monochrome or color-averse terminal.
+    fn plain(name: &str, border_type: BorderType) -> Self {
+        Theme {
+            name: name.to_string(),
+            selected_fg: Color::Reset,
+            selected_bg: Color::Reset,
+            unselected_fg: Color::Reset,
+            unselected_bg: Color::Reset,
+            border: Color::Reset,
+            disabled: Color::Reset,
+            accent: Color::Reset,
+            error: Color::Reset,
+            warning: Color::Reset,
+            editing_cursor: Color::Reset,
+            border_type,
+        }
+    }
+
+    /// <https://no-color.org>: any non-empty value disables color.
+    fn no_color() -> bool {
+        std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+    }
+
+    /// Flatten to `plain` when `NO_COLOR` is set. Every path that finalizes
+    /// a theme for display — built-in, `theme.toml`-overlaid, or a saved
+    /// custom theme — runs through this so the env var applies no matter
+    /// which one the user picked. `border_type` survives the flatten since
+    /// it's a glyph choice, not a color.
+    pub fn apply_no_color(self) -> Self {
+        if Self::no_color() {
+            Theme::plain(&self.name, self.border_type)
+        } else {
+            self
+        }
+    }
+
+    /// Resolve the active theme: start from the built-in matching
+    /// `active_name` (falling back to "default" if unknown), then overlay
+    /// `~/.config/artgg/theme.toml` if the user has one, then flatten to
+    /// `plain` if `NO_COLOR` is set.
+    pub fn load(active_name: &str) -> Result<Self> {
+        let mut theme = Theme::builtin(active_name);
+        if let Ok(text) = fs::read_to_string(Self::config_path()) {
+            theme = apply_overlay(theme, &text);
+        }
+        Ok(theme.apply_no_color())
+    }
+}
+
+/// Map a ratatui `Color` back to RGB for hex serialization — covers
+/// `Color::Rgb` plus the handful of named variants the built-in presets and
+/// `theme.toml` overlay use; anything else (e.g. an indexed terminal color)
+/// falls back to black rather than failing the save.
+fn color_to_rgb(c: Color) -> (u8, u8, u8) {
+    match c {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black | Color::Reset => (0x00, 0x00, 0x00),
+        Color::White => (0xFF, 0xFF, 0xFF),
+        Color::Red => (0xFF, 0x00, 0x00),
+        Color::Green => (0x00, 0x80, 0x00),
+        Color::Yellow => (0xFF, 0xFF, 0x00),
+        Color::Blue => (0x00, 0x00, 0xFF),
+        Color::Magenta => (0xFF, 0x00, 0xFF),
+        Color::Cyan => (0x00, 0xFF, 0xFF),
+        Color::Gray => (0x80, 0x80, 0x80),
+        Color::DarkGray => (0x40, 0x40, 0x40),
+        _ => (0x00, 0x00, 0x00),
+    }
+}
+
+/// Minimal `key = "value"` overlay parser — a theme.toml only ever sets a
+/// handful of flat color fields, so a full TOML parser would be overkill.
+fn apply_overlay(mut theme: Theme, text: &str) -> Theme {
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim().trim_matches('"');
+        if key == "name" {
+            theme.name = value.to_string();
+            continue;
+        }
+        if key == "border_type" {
+            if let Some(bt) = parse_border_type(value) {
+                theme.border_type = bt;
+            }
+            continue;
+        }
+        let Some((r, g, b)) = crate::color::parse_color(value) else {
+            continue;
+        };
+        let color = Color::Rgb(r, g, b);
+        match key {
+            "selected_fg" => theme.selected_fg = color,
+            "selected_bg" => theme.selected_bg = color,
+            "unselected_fg" => theme.unselected_fg = color,
+            "unselected_bg" => theme.unselected_bg = color,
+            "border" => theme.border = color,
+            "disabled" => theme.disabled = color,
+            "accent" => theme.accent = color,
+            "error" => theme.error = color,
+            "warning" => theme.warning = color,
+            "editing_cursor" => theme.editing_cursor = color,
+            _ => {}
+        }
+    }
+    theme
+}
+
+/// `theme.toml`'s `border_type` value, case-insensitively, falling back to
+/// `None` (leaving the built-in's own border type in place) for anything
+/// unrecognized rather than failing the whole overlay.
+fn parse_border_type(value: &str) -> Option<BorderType> {
+    match value.to_ascii_lowercase().as_str() {
+        "rounded" => Some(BorderType::Rounded),
+        "plain" => Some(BorderType::Plain),
+        "double" => Some(BorderType::Double),
+        "thick" => Some(BorderType::Thick),
+        _ => None,
+    }
+}
@@ -2,18 +2,163 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{
+        Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Wrap,
+    },
     Frame,
 };
 
-use crate::app::{App, BuildStep, DisplayScreenMode, MainItem, Screen, TasteScreenMode};
+use crate::app::{
+    App, BuildStep, ColorPickerState, DisplayScreenMode, FrameStyle, HistoryEntry, MainItem,
+    PreviewCache, Screen, TasteScreenMode, ThemeScreenMode,
+};
+use crate::artwork;
+use crate::dirbrowse;
+use crate::theme::Theme;
+
+/// Staged-gallery confirmation step: summarizes the merged keyword set and
+/// date range on the left, with a live preview of the candidate artwork on
+/// the right.
+fn draw_confirm_stage(frame: &mut Frame, app: &mut App, area: Rect) {
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(area);
+
+    let merged = app.merged_taste_selection();
+    let range = match (merged.date_start, merged.date_end) {
+        (Some(s), Some(e)) => format!("{}–{}", s, e),
+        (Some(s), None) => format!("from {}", s),
+        (None, Some(e)) => format!("until {}", e),
+        (None, None) => "(any)".to_string(),
+    };
+    let status = app.artwork_worker.latest();
+    let artwork_line = match &status {
+        artwork::ArtworkStatus::Loading => {
+            Line::from(Span::styled("Artwork: resolving…", Style::default().fg(Color::DarkGray)))
+        }
+        artwork::ArtworkStatus::Ready(resolved) => Line::from(format!(
+            "Artwork: {} profile(s), {} keyword(s) resolved",
+            resolved.profile_names.len(),
+            resolved.keyword_count
+        )),
+    };
+    let lines = vec![
+        Line::from(format!("Profiles: {}", merged.names.join(" + "))),
+        Line::from(format!("Keywords: {}", merged.keywords.join(", "))),
+        Line::from(format!("Date range: {}", range)),
+        Line::from(format!(
+            "Public domain only: {}",
+            if merged.is_public_domain { "Yes" } else { "No" }
+        )),
+        artwork_line,
+    ];
+    let panel = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(" Staged Gallery "),
+    );
+    frame.render_widget(panel, panes[0]);
+
+    let ready = match &status {
+        artwork::ArtworkStatus::Ready(resolved) => Some((resolved.image_id, &resolved.image)),
+        artwork::ArtworkStatus::Loading => None,
+    };
+    render_image_preview(frame, panes[1], ready, &mut app.preview_cache);
+}
+
+/// Downsamples `image` to `area`'s inner cell grid and draws it with
+/// Unicode half-blocks (▀): each cell's foreground carries the upper of
+/// two source pixel rows and its background the lower, so one row of
+/// terminal cells shows two rows of image. Draws a "preview unavailable"
+/// panel instead when no image has resolved yet.
+fn render_image_preview(
+    frame: &mut Frame,
+    area: Rect,
+    image: Option<(u64, &artwork::RgbImage)>,
+    cache: &mut PreviewCache,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(" Preview ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some((image_id, source)) = image else {
+        let msg = Paragraph::new("preview unavailable")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(msg, inner);
+        return;
+    };
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
 
-pub fn draw(frame: &mut Frame, app: &App) {
+    let scaled = cache.scaled(image_id, source, inner.width, inner.height);
+    let mut lines = Vec::with_capacity(inner.height as usize);
+    for row in 0..inner.height {
+        let mut spans = Vec::with_capacity(inner.width as usize);
+        for col in 0..inner.width {
+            let (tr, tg, tb) = scaled.pixel(col as u32, (row * 2) as u32);
+            let (br, bg, bb) = scaled.pixel(col as u32, (row * 2 + 1) as u32);
+            spans.push(Span::styled(
+                "▀",
+                Style::default().fg(Color::Rgb(tr, tg, tb)).bg(Color::Rgb(br, bg, bb)),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Record one hitbox per row `area` will show for a list of `len` items,
+/// `focus` being the currently-selected index (the same one passed to the
+/// matching `List`'s `ListState`). Mirrors the auto-scroll ratatui's
+/// `List` applies internally once `focus` no longer fits in the viewport
+/// — without this, rows are assigned as if the list never scrolled and a
+/// click on a visible row resolves to the wrong item as soon as the list
+/// is longer than `area` is tall. Called right before the matching `List`
+/// is built, so the click and hover handling below can query
+/// `app.hitboxes` for this very frame's layout instead of a cached one.
+fn record_list_hitboxes(app: &mut App, screen: Screen, area: Rect, len: usize, focus: usize) {
+    if area.width < 2 || area.height < 2 {
+        return;
+    }
+    let visible_rows = (area.height - 2) as usize;
+    let offset = focus.saturating_sub(visible_rows.saturating_sub(1));
+    for i in 0..len.saturating_sub(offset).min(visible_rows) {
+        let rect = Rect { x: area.x + 1, y: area.y + 1 + i as u16, width: area.width - 2, height: 1 };
+        app.hitboxes.push(screen, offset + i, rect);
+    }
+}
+
+/// Style to layer onto a row that's hovered but not already focused — an
+/// underline reads as "about to be clicked" without fighting whatever
+/// foreground color the row already carries (e.g. the staged-item yellow).
+fn with_hover(style: Style, hovered: bool) -> Style {
+    if hovered {
+        style.add_modifier(Modifier::UNDERLINED)
+    } else {
+        style
+    }
+}
+
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    // Rebuilt fresh every frame so hit-testing always reflects the layout
+    // that's actually about to be painted, not a stale one from last frame.
+    app.hitboxes.clear();
     match app.screen {
         Screen::Main => draw_main(frame, app),
         Screen::TasteProfiles => draw_taste_profiles(frame, app),
         Screen::DisplayProfiles => draw_display_profiles(frame, app),
         Screen::Build => draw_build(frame, app),
+        Screen::Theme => draw_theme(frame, app),
     }
 }
 
@@ -30,29 +175,75 @@ fn base_layout(frame: &Frame) -> (Rect, Rect, Rect) {
     (chunks[0], chunks[1], chunks[2])
 }
 
-fn render_header(frame: &mut Frame, area: Rect, subtitle: &str) {
-    let header = Paragraph::new(Line::from(vec![
+/// Title line plus the persistent tab strip beneath it — the two share
+/// `base_layout`'s `Length(3)` header area (1 row title, 1 row tabs, 1 row
+/// border) so every screen gets one-keystroke `Tab`/`Shift-Tab` movement
+/// between sections without the border eating into either row.
+fn render_header(frame: &mut Frame, app: &mut App, area: Rect, subtitle: &str) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(2)])
+        .split(area);
+
+    let theme = &app.theme;
+    let title = Paragraph::new(Line::from(vec![
         Span::styled(
             "art",
-            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.unselected_fg).add_modifier(Modifier::BOLD),
         ),
         Span::styled(
             "gg",
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
         ),
         Span::raw(format!("  ·  {}", subtitle)),
     ]))
-    .alignment(Alignment::Center)
-    .block(
+    .alignment(Alignment::Center);
+    frame.render_widget(title, rows[0]);
+
+    render_tab_bar(frame, app, rows[1]);
+}
+
+/// Renders `Screen::TABS` as a single centered, divider-separated line and
+/// records each tab's rect into `app.tab_hitboxes` so a click lands on the
+/// same row the text was actually centered into this frame.
+fn render_tab_bar(frame: &mut Frame, app: &mut App, area: Rect) {
+    const DIVIDER: &str = " │ ";
+    app.tab_hitboxes.clear();
+
+    let divider_width = DIVIDER.chars().count() as u16;
+    let total_width: u16 = Screen::TABS.iter().map(|s| s.tab_label().chars().count() as u16).sum::<u16>()
+        + divider_width * Screen::TABS.len().saturating_sub(1) as u16;
+    let mut x = area.x + area.width.saturating_sub(total_width) / 2;
+
+    let theme = app.theme.clone();
+    let mut spans = Vec::with_capacity(Screen::TABS.len() * 2);
+    for (i, &screen) in Screen::TABS.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(DIVIDER, Style::default().fg(theme.border)));
+            x += divider_width;
+        }
+        let label = screen.tab_label();
+        let width = label.chars().count() as u16;
+        app.tab_hitboxes.push((screen, Rect { x, y: area.y, width, height: 1 }));
+        let style = if screen == app.screen {
+            Style::default().fg(theme.selected_fg).bg(theme.selected_bg).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.unselected_fg)
+        };
+        spans.push(Span::styled(label, style));
+        x += width;
+    }
+
+    let tabs = Paragraph::new(Line::from(spans)).alignment(Alignment::Center).block(
         Block::default()
             .borders(Borders::BOTTOM)
             .border_type(BorderType::Plain)
-            .border_style(Style::default().fg(Color::DarkGray)),
+            .border_style(Style::default().fg(theme.border)),
     );
-    frame.render_widget(header, area);
+    frame.render_widget(tabs, area);
 }
 
-fn render_footer(frame: &mut Frame, area: Rect, hints: &[(&str, &str)]) {
+fn render_footer(frame: &mut Frame, area: Rect, hints: &[(&str, &str)], theme: &Theme) {
     let mut spans: Vec<Span> = Vec::new();
     for (i, (key, desc)) in hints.iter().enumerate() {
         if i > 0 {
@@ -60,7 +251,7 @@ fn render_footer(frame: &mut Frame, area: Rect, hints: &[(&str, &str)]) {
         }
         spans.push(Span::styled(
             format!(" {} ", key),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme.accent),
         ));
         spans.push(Span::raw(desc.to_string()));
     }
@@ -70,89 +261,151 @@ fn render_footer(frame: &mut Frame, area: Rect, hints: &[(&str, &str)]) {
             Block::default()
                 .borders(Borders::TOP)
                 .border_type(BorderType::Plain)
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(theme.border)),
         );
     frame.render_widget(footer, area);
 }
 
-fn draw_main(frame: &mut Frame, app: &App) {
+/// Footer that shows the `:`-command prompt (or its last result) instead of
+/// the usual key hints whenever the command palette is open.
+fn render_footer_or_command(frame: &mut Frame, area: Rect, hints: &[(&str, &str)], app: &App) {
+    if let Some(buf) = &app.command_line {
+        let prompt = Paragraph::new(Line::from(format!(":{}", buf)))
+            .alignment(Alignment::Left)
+            .block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .border_type(BorderType::Plain)
+                    .border_style(Style::default().fg(app.theme.border)),
+            );
+        frame.render_widget(prompt, area);
+    } else if let Some(status) = &app.command_status {
+        let result = Paragraph::new(Line::from(status.as_str()))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(app.theme.accent))
+            .block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .border_type(BorderType::Plain)
+                    .border_style(Style::default().fg(app.theme.border)),
+            );
+        frame.render_widget(result, area);
+    } else {
+        render_footer(frame, area, hints, &app.theme);
+    }
+}
+
+fn draw_main(frame: &mut Frame, app: &mut App) {
     let (header_area, body_area, footer_area) = base_layout(frame);
-    render_header(frame, header_area, "Classical artwork wallpaper generator");
+    render_header(frame, app, header_area, "Classical artwork wallpaper generator");
 
     let body = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Length(22), Constraint::Min(0)])
         .split(body_area.inner(Margin { horizontal: 2, vertical: 1 }));
 
+    record_list_hitboxes(app, Screen::Main, body[0], MainItem::ALL.len(), app.main_scroll.focus);
+    let hovered = app.mouse_pos.and_then(|(col, row)| app.hitboxes.hit(Screen::Main, col, row));
+
     let items: Vec<ListItem> = MainItem::ALL
         .iter()
-        .map(|item| {
+        .enumerate()
+        .map(|(i, item)| {
+            let is_hovered = hovered == Some(i) && i != app.main_scroll.focus;
             if item.is_disabled() {
-                ListItem::new(item.label()).style(Style::default().fg(Color::DarkGray))
+                ListItem::new(item.label()).style(Style::default().fg(app.theme.disabled))
             } else {
-                ListItem::new(item.label())
+                let style = with_hover(Style::default().fg(app.theme.unselected_fg), is_hovered);
+                ListItem::new(item.label()).style(style)
             }
         })
         .collect();
 
     let mut list_state = ListState::default();
-    list_state.select(Some(app.main_selected));
+    list_state.select(Some(app.main_scroll.focus));
 
     let list = List::new(items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(app.theme.border))
                 .title(" Menu "),
         )
-        .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .highlight_style(
+            Style::default().fg(app.theme.selected_fg).bg(app.theme.selected_bg).add_modifier(Modifier::BOLD),
+        )
         .highlight_symbol("> ");
 
     frame.render_stateful_widget(list, body[0], &mut list_state);
 
-    let selected_item = MainItem::ALL[app.main_selected];
+    let selected_item = MainItem::ALL[app.main_scroll.focus];
     let detail = Paragraph::new(selected_item.description())
         .wrap(Wrap { trim: true })
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(app.theme.border))
                 .title(format!(" {} ", selected_item.label())),
         );
     frame.render_widget(detail, body[1]);
 
-    render_footer(
+    render_footer_or_command(
         frame,
         footer_area,
-        &[("↑↓", "navigate"), ("Enter", "select"), ("q", "quit")],
+        &[("↑↓", "navigate"), ("Enter", "select"), ("Tab", "section"), (":", "command"), ("q", "quit")],
+        app,
     );
 }
 
 // ─── Taste Profiles ───────────────────────────────────────────────────────────
 
-fn draw_taste_profiles(frame: &mut Frame, app: &App) {
+fn draw_taste_profiles(frame: &mut Frame, app: &mut App) {
     let (header_area, body_area, footer_area) = base_layout(frame);
-    render_header(frame, header_area, "Taste Profiles");
+    render_header(frame, app, header_area, "Taste Profiles");
 
     let body = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
         .split(body_area.inner(Margin { horizontal: 2, vertical: 1 }));
 
-    // Left pane: profile list
+    // Left pane: profile list, narrowed to the matching indices while searching
+    let visible_indices: Vec<usize> = match &app.taste_mode {
+        TasteScreenMode::Searching(_, matches) => matches.clone(),
+        _ => (0..app.taste_profiles.len()).collect(),
+    };
+    let left_title = match &app.taste_mode {
+        TasteScreenMode::Searching(query, _) => format!(" Taste Profiles — /{} ", query),
+        _ => " Taste Profiles ".to_string(),
+    };
+    record_list_hitboxes(app, Screen::TasteProfiles, body[0], visible_indices.len(), app.taste_scroll.focus);
+    let hovered =
+        app.mouse_pos.and_then(|(col, row)| app.hitboxes.hit(Screen::TasteProfiles, col, row));
     let left_items: Vec<ListItem> = if app.taste_profiles.is_empty() {
         vec![ListItem::new("(none)").style(Style::default().fg(Color::DarkGray))]
+    } else if visible_indices.is_empty() {
+        vec![ListItem::new("(no matches)").style(Style::default().fg(Color::DarkGray))]
     } else {
-        app.taste_profiles
+        visible_indices
             .iter()
-            .map(|p| ListItem::new(p.name.as_str()))
+            .enumerate()
+            .map(|(pos, &i)| {
+                let p = &app.taste_profiles[i];
+                let is_hovered = hovered == Some(pos) && pos != app.taste_scroll.focus;
+                if app.stage.contains(p.id) {
+                    ListItem::new(format!("● {}", p.name))
+                        .style(with_hover(Style::default().fg(Color::Yellow), is_hovered))
+                } else {
+                    ListItem::new(format!("  {}", p.name))
+                        .style(with_hover(Style::default(), is_hovered))
+                }
+            })
             .collect()
     };
     let mut left_state = ListState::default();
-    if !app.taste_profiles.is_empty() {
-        left_state.select(Some(app.taste_selected));
+    if !visible_indices.is_empty() {
+        left_state.select(Some(app.taste_scroll.focus));
     }
     let left_list = List::new(left_items)
         .block(
@@ -160,11 +413,12 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(Color::DarkGray))
-                .title(" Taste Profiles "),
+                .title(left_title),
         )
         .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
         .highlight_symbol("> ");
     frame.render_stateful_widget(left_list, body[0], &mut left_state);
+    render_scrollbar(frame, body[0], visible_indices.len(), app.taste_scroll.focus);
 
     // Right pane
     match &app.taste_mode {
@@ -190,10 +444,10 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
                 );
                 frame.render_widget(info, body[1]);
             } else {
-                let p = &app.taste_profiles[app.taste_selected];
+                let p = &app.taste_profiles[app.taste_scroll.focus];
                 let items = build_taste_detail_items(
-                    p.date_start, p.date_end, p.is_public_domain, p.keywords.len(),
-                    None, "",
+                    p.date_start, p.date_end, p.is_public_domain, p.keywords.len(), p.artists.len(),
+                    None, "", app.theme.disabled,
                 );
                 let list = List::new(items).block(
                     Block::default()
@@ -206,11 +460,32 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
             }
         }
 
+        TasteScreenMode::Searching(query, matches) => {
+            let info = if let Some(&idx) = matches.get(app.taste_scroll.focus) {
+                let p = &app.taste_profiles[idx];
+                Paragraph::new(vec![
+                    Line::from(format!("{} matches", matches.len())),
+                    Line::from(""),
+                    Line::from(p.name.as_str()),
+                ])
+            } else {
+                Paragraph::new(format!("No profiles match \"{}\"", query))
+            };
+            let panel = info.wrap(Wrap { trim: true }).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::DarkGray))
+                    .title(" Search "),
+            );
+            frame.render_widget(panel, body[1]);
+        }
+
         TasteScreenMode::Detail => {
-            let p = &app.taste_profiles[app.taste_selected];
+            let p = &app.taste_profiles[app.taste_scroll.focus];
             let items = build_taste_detail_items(
-                p.date_start, p.date_end, p.is_public_domain, p.keywords.len(),
-                None, "",
+                p.date_start, p.date_end, p.is_public_domain, p.keywords.len(), p.artists.len(),
+                None, "", app.theme.disabled,
             );
             let mut state = ListState::default();
             state.select(Some(app.taste_detail_field));
@@ -227,11 +502,40 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
             frame.render_stateful_widget(list, body[1], &mut state);
         }
 
+        TasteScreenMode::History => {
+            let p = &app.taste_profiles[app.taste_scroll.focus];
+            let items = history_list_items(&app.taste_history, |e| {
+                vec![
+                    format!(
+                        "start {} end {} public-domain {}",
+                        e.date_start.map(|v| v.to_string()).unwrap_or_else(|| "—".into()),
+                        e.date_end.map(|v| v.to_string()).unwrap_or_else(|| "—".into()),
+                        e.is_public_domain,
+                    ),
+                ]
+            });
+            let mut state = ListState::default();
+            if !app.taste_history.is_empty() {
+                state.select(Some(app.taste_history_scroll.focus));
+            }
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(Color::Yellow))
+                        .title(format!(" {} — History ", p.name)),
+                )
+                .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                .highlight_symbol("> ");
+            frame.render_stateful_widget(list, body[1], &mut state);
+        }
+
         TasteScreenMode::EditingDate(buf) => {
-            let p = &app.taste_profiles[app.taste_selected];
+            let p = &app.taste_profiles[app.taste_scroll.focus];
             let items = build_taste_detail_items(
-                p.date_start, p.date_end, p.is_public_domain, p.keywords.len(),
-                Some(app.taste_detail_field), buf,
+                p.date_start, p.date_end, p.is_public_domain, p.keywords.len(), p.artists.len(),
+                Some(app.taste_detail_field), buf, app.theme.disabled,
             );
             let mut state = ListState::default();
             state.select(Some(app.taste_detail_field));
@@ -249,14 +553,43 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
         }
 
         TasteScreenMode::SelectingKeywords => {
-            let p = &app.taste_profiles[app.taste_selected];
-            render_keyword_picker(frame, body[1], &app.available_keywords, &p.keywords, app.keyword_cursor);
+            let p = &app.taste_profiles[app.taste_scroll.focus];
+            let all: Vec<usize> = (0..app.available_keywords.len()).collect();
+            render_keyword_picker(
+                frame, body[1], &app.available_keywords, &all, &p.keywords,
+                app.keyword_scroll.focus, None, &app.theme,
+            );
+        }
+
+        TasteScreenMode::KeywordSearch(query, matches) => {
+            let p = &app.taste_profiles[app.taste_scroll.focus];
+            render_keyword_picker(
+                frame, body[1], &app.available_keywords, matches, &p.keywords,
+                app.keyword_scroll.focus, Some(query), &app.theme,
+            );
+        }
+
+        TasteScreenMode::SelectingArtists => {
+            let p = &app.taste_profiles[app.taste_scroll.focus];
+            let all: Vec<usize> = (0..app.available_artists.len()).collect();
+            render_artist_picker(
+                frame, body[1], &app.available_artists, &all, &p.artists,
+                app.artist_scroll.focus, None, &app.theme,
+            );
+        }
+
+        TasteScreenMode::ArtistSearch(query, matches) => {
+            let p = &app.taste_profiles[app.taste_scroll.focus];
+            render_artist_picker(
+                frame, body[1], &app.available_artists, matches, &p.artists,
+                app.artist_scroll.focus, Some(query), &app.theme,
+            );
         }
 
         TasteScreenMode::CreatingProfile => {
             let d = &app.new_taste_draft;
             let items = build_taste_creating_items(
-                d.date_start, d.date_end, d.is_public_domain, d.keywords.len(),
+                d.date_start, d.date_end, d.is_public_domain, d.keywords.len(), d.artists.len(),
                 &d.name, None, "",
             );
             let mut state = ListState::default();
@@ -277,7 +610,7 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
         TasteScreenMode::CreatingEditDate(buf) => {
             let d = &app.new_taste_draft;
             let items = build_taste_creating_items(
-                d.date_start, d.date_end, d.is_public_domain, d.keywords.len(),
+                d.date_start, d.date_end, d.is_public_domain, d.keywords.len(), d.artists.len(),
                 &d.name, Some(d.current_field), buf,
             );
             let mut state = ListState::default();
@@ -296,22 +629,63 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
         }
 
         TasteScreenMode::CreatingSelectKeywords => {
+            let all: Vec<usize> = (0..app.available_keywords.len()).collect();
+            render_keyword_picker(
+                frame, body[1],
+                &app.available_keywords,
+                &all,
+                &app.new_taste_draft.keywords,
+                app.keyword_scroll.focus,
+                None,
+                &app.theme,
+            );
+        }
+
+        TasteScreenMode::CreatingKeywordSearch(query, matches) => {
             render_keyword_picker(
                 frame, body[1],
                 &app.available_keywords,
+                matches,
                 &app.new_taste_draft.keywords,
-                app.keyword_cursor,
+                app.keyword_scroll.focus,
+                Some(query),
+                &app.theme,
+            );
+        }
+
+        TasteScreenMode::CreatingSelectArtists => {
+            let all: Vec<usize> = (0..app.available_artists.len()).collect();
+            render_artist_picker(
+                frame, body[1],
+                &app.available_artists,
+                &all,
+                &app.new_taste_draft.artists,
+                app.artist_scroll.focus,
+                None,
+                &app.theme,
+            );
+        }
+
+        TasteScreenMode::CreatingArtistSearch(query, matches) => {
+            render_artist_picker(
+                frame, body[1],
+                &app.available_artists,
+                matches,
+                &app.new_taste_draft.artists,
+                app.artist_scroll.focus,
+                Some(query),
+                &app.theme,
             );
         }
 
         TasteScreenMode::CreatingName(buf) => {
             let d = &app.new_taste_draft;
             let items = build_taste_creating_items(
-                d.date_start, d.date_end, d.is_public_domain, d.keywords.len(),
-                buf, Some(4), buf,
+                d.date_start, d.date_end, d.is_public_domain, d.keywords.len(), d.artists.len(),
+                buf, Some(5), buf,
             );
             let mut state = ListState::default();
-            state.select(Some(4));
+            state.select(Some(5));
             let list = List::new(items)
                 .block(
                     Block::default()
@@ -330,7 +704,7 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
     let kw_count = match &app.taste_mode {
         TasteScreenMode::SelectingKeywords => {
             if !app.taste_profiles.is_empty() {
-                app.taste_profiles[app.taste_selected].keywords.len()
+                app.taste_profiles[app.taste_scroll.focus].keywords.len()
             } else {
                 0
             }
@@ -339,53 +713,106 @@ fn draw_taste_profiles(frame: &mut Frame, app: &App) {
         _ => 0,
     };
     let toggle_hint = format!("toggle ({}/10)", kw_count);
+    let artist_count = match &app.taste_mode {
+        TasteScreenMode::SelectingArtists if !app.taste_profiles.is_empty() => {
+            app.taste_profiles[app.taste_scroll.focus].artists.len()
+        }
+        TasteScreenMode::CreatingSelectArtists => app.new_taste_draft.artists.len(),
+        _ => 0,
+    };
+    let artist_toggle_hint = format!("toggle ({}/∞)", artist_count);
     let footer_hints: Vec<(&str, &str)> = match &app.taste_mode {
         TasteScreenMode::Browse if app.taste_profiles.is_empty() => {
             vec![("a", "add"), ("Esc", "back")]
         }
         TasteScreenMode::Browse => vec![
-            ("↑↓", "select"), ("Enter", "edit"), ("a", "add"), ("d", "delete"), ("Esc", "back"),
+            ("↑↓", "select"), ("Enter", "edit"), ("a", "add"), ("s", "stage"),
+            ("d", "delete"), ("/", "search"), (":", "command"), ("Esc", "back"),
         ],
-        TasteScreenMode::Detail => vec![("↑↓", "navigate"), ("Enter", "edit"), ("Esc", "back")],
+        TasteScreenMode::Searching(_, _) => {
+            vec![("type", "filter"), ("↑↓", "select match"), ("Enter", "open"), ("Esc", "cancel")]
+        }
+        TasteScreenMode::Detail => {
+            vec![("↑↓", "navigate"), ("Enter", "edit"), ("h", "history"), ("Esc", "back")]
+        }
+        TasteScreenMode::History => {
+            vec![("↑↓", "select"), ("Enter", "restore"), ("Esc", "back")]
+        }
         TasteScreenMode::EditingDate(_) => vec![("Enter", "confirm"), ("Esc", "cancel")],
         TasteScreenMode::SelectingKeywords => vec![
-            ("↑↓", "navigate"), ("Space", toggle_hint.as_str()), ("Esc", "done"),
+            ("↑↓", "navigate"), ("Space", toggle_hint.as_str()), ("/", "search"), ("Esc", "done"),
         ],
+        TasteScreenMode::KeywordSearch(_, _) => {
+            vec![("type", "filter"), ("↑↓", "select match"), ("Enter", "toggle"), ("Esc", "cancel")]
+        }
+        TasteScreenMode::SelectingArtists => vec![
+            ("↑↓", "navigate"), ("Space", artist_toggle_hint.as_str()), ("/", "search"), ("Esc", "done"),
+        ],
+        TasteScreenMode::ArtistSearch(_, _) => {
+            vec![("type", "filter"), ("↑↓", "select match"), ("Enter", "toggle"), ("Esc", "cancel")]
+        }
         TasteScreenMode::CreatingProfile => {
             vec![("↑↓", "navigate"), ("Enter", "select"), ("Esc", "cancel")]
         }
         TasteScreenMode::CreatingEditDate(_) => vec![("Enter", "confirm"), ("Esc", "cancel")],
         TasteScreenMode::CreatingSelectKeywords => vec![
-            ("↑↓", "navigate"), ("Space", toggle_hint.as_str()), ("Esc", "done"),
+            ("↑↓", "navigate"), ("Space", toggle_hint.as_str()), ("/", "search"), ("Esc", "done"),
         ],
+        TasteScreenMode::CreatingKeywordSearch(_, _) => {
+            vec![("type", "filter"), ("↑↓", "select match"), ("Enter", "toggle"), ("Esc", "cancel")]
+        }
+        TasteScreenMode::CreatingSelectArtists => vec![
+            ("↑↓", "navigate"), ("Space", artist_toggle_hint.as_str()), ("/", "search"), ("Esc", "done"),
+        ],
+        TasteScreenMode::CreatingArtistSearch(_, _) => {
+            vec![("type", "filter"), ("↑↓", "select match"), ("Enter", "toggle"), ("Esc", "cancel")]
+        }
         TasteScreenMode::CreatingName(_) => vec![("Enter", "confirm"), ("Esc", "cancel")],
     };
-    render_footer(frame, footer_area, &footer_hints);
+    render_footer_or_command(frame, footer_area, &footer_hints, app);
 }
 
 // ─── Display Profiles ─────────────────────────────────────────────────────────
 
-fn draw_display_profiles(frame: &mut Frame, app: &App) {
+fn draw_display_profiles(frame: &mut Frame, app: &mut App) {
     let (header_area, body_area, footer_area) = base_layout(frame);
-    render_header(frame, header_area, "Display Profiles");
+    render_header(frame, app, header_area, "Display Profiles");
 
     let body = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
         .split(body_area.inner(Margin { horizontal: 2, vertical: 1 }));
 
-    // Left pane
+    // Left pane, narrowed to the matching indices while searching
+    let visible_indices: Vec<usize> = match &app.display_mode {
+        DisplayScreenMode::Searching(_, matches) => matches.clone(),
+        _ => (0..app.display_profiles.len()).collect(),
+    };
+    let left_title = match &app.display_mode {
+        DisplayScreenMode::Searching(query, _) => format!(" Display Profiles — /{} ", query),
+        _ => " Display Profiles ".to_string(),
+    };
+    record_list_hitboxes(app, Screen::DisplayProfiles, body[0], visible_indices.len(), app.display_scroll.focus);
+    let hovered =
+        app.mouse_pos.and_then(|(col, row)| app.hitboxes.hit(Screen::DisplayProfiles, col, row));
     let left_items: Vec<ListItem> = if app.display_profiles.is_empty() {
         vec![ListItem::new("(none)").style(Style::default().fg(Color::DarkGray))]
+    } else if visible_indices.is_empty() {
+        vec![ListItem::new("(no matches)").style(Style::default().fg(Color::DarkGray))]
     } else {
-        app.display_profiles
+        visible_indices
             .iter()
-            .map(|p| ListItem::new(p.name.as_str()))
+            .enumerate()
+            .map(|(pos, &i)| {
+                let is_hovered = hovered == Some(pos) && pos != app.display_scroll.focus;
+                ListItem::new(app.display_profiles[i].name.as_str())
+                    .style(with_hover(Style::default(), is_hovered))
+            })
             .collect()
     };
     let mut left_state = ListState::default();
-    if !app.display_profiles.is_empty() {
-        left_state.select(Some(app.display_selected));
+    if !visible_indices.is_empty() {
+        left_state.select(Some(app.display_scroll.focus));
     }
     let left_list = List::new(left_items)
         .block(
@@ -393,11 +820,12 @@ fn draw_display_profiles(frame: &mut Frame, app: &App) {
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(Color::DarkGray))
-                .title(" Display Profiles "),
+                .title(left_title),
         )
         .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
         .highlight_symbol("> ");
     frame.render_stateful_widget(left_list, body[0], &mut left_state);
+    render_scrollbar(frame, body[0], visible_indices.len(), app.display_scroll.focus);
 
     // Right pane
     match &app.display_mode {
@@ -423,10 +851,10 @@ fn draw_display_profiles(frame: &mut Frame, app: &App) {
                 );
                 frame.render_widget(info, body[1]);
             } else {
-                let p = &app.display_profiles[app.display_selected];
+                let p = &app.display_profiles[app.display_scroll.focus];
                 let items = build_display_detail_items(
-                    &p.wallpaper_color, &p.orientation, &p.aspect_ratio,
-                    None, "",
+                    &p.wallpaper_color, &p.frame_style, &p.orientation, &p.aspect_ratio,
+                    None, "", app.theme.disabled,
                 );
                 let list = List::new(items).block(
                     Block::default()
@@ -439,11 +867,37 @@ fn draw_display_profiles(frame: &mut Frame, app: &App) {
             }
         }
 
+        DisplayScreenMode::Searching(query, matches) => {
+            let info = if let Some(&idx) = matches.get(app.display_scroll.focus) {
+                let p = &app.display_profiles[idx];
+                Paragraph::new(vec![
+                    Line::from(format!("{} matches", matches.len())),
+                    Line::from(""),
+                    Line::from(p.name.as_str()),
+                ])
+            } else {
+                Paragraph::new(format!("No profiles match \"{}\"", query))
+            };
+            let panel = info.wrap(Wrap { trim: true }).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::DarkGray))
+                    .title(" Search "),
+            );
+            frame.render_widget(panel, body[1]);
+        }
+
         DisplayScreenMode::Detail => {
-            let p = &app.display_profiles[app.display_selected];
+            let panes = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(6), Constraint::Length(7)])
+                .split(body[1]);
+            app.display_detail_rect = panes[0];
+            let p = &app.display_profiles[app.display_scroll.focus];
             let items = build_display_detail_items(
-                &p.wallpaper_color, &p.orientation, &p.aspect_ratio,
-                None, "",
+                &p.wallpaper_color, &p.frame_style, &p.orientation, &p.aspect_ratio,
+                None, "", app.theme.disabled,
             );
             let mut state = ListState::default();
             state.select(Some(app.display_detail_field));
@@ -457,14 +911,28 @@ fn draw_display_profiles(frame: &mut Frame, app: &App) {
                 )
                 .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
                 .highlight_symbol("> ");
-            frame.render_stateful_widget(list, body[1], &mut state);
+            frame.render_stateful_widget(list, panes[0], &mut state);
+            let preview_panes = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(panes[1]);
+            render_frame_preview(
+                frame, preview_panes[0],
+                FrameStyle::from_str(&p.frame_style), &p.orientation, &p.aspect_ratio,
+            );
+            let status = app.artwork_worker.latest();
+            let ready = match &status {
+                artwork::ArtworkStatus::Ready(resolved) => Some((resolved.image_id, &resolved.image)),
+                artwork::ArtworkStatus::Loading => None,
+            };
+            render_image_preview(frame, preview_panes[1], ready, &mut app.preview_cache);
         }
 
         DisplayScreenMode::EditingText(buf) => {
-            let p = &app.display_profiles[app.display_selected];
+            let p = &app.display_profiles[app.display_scroll.focus];
             let items = build_display_detail_items(
-                &p.wallpaper_color, &p.orientation, &p.aspect_ratio,
-                Some(app.display_detail_field), buf,
+                &p.wallpaper_color, &p.frame_style, &p.orientation, &p.aspect_ratio,
+                Some(app.display_detail_field), buf, app.theme.disabled,
             );
             let mut state = ListState::default();
             state.select(Some(app.display_detail_field));
@@ -481,11 +949,47 @@ fn draw_display_profiles(frame: &mut Frame, app: &App) {
             frame.render_stateful_widget(list, body[1], &mut state);
         }
 
+        DisplayScreenMode::EditingColor(picker) => {
+            let p = &app.display_profiles[app.display_scroll.focus];
+            render_color_picker(frame, body[1], &p.name, picker);
+        }
+
+        DisplayScreenMode::History => {
+            let p = &app.display_profiles[app.display_scroll.focus];
+            let items = history_list_items(&app.display_history, |e| {
+                vec![
+                    format!(
+                        "{} / {} / {} / {}",
+                        e.wallpaper_color, e.frame_style, e.orientation, e.aspect_ratio,
+                    ),
+                ]
+            });
+            let mut state = ListState::default();
+            if !app.display_history.is_empty() {
+                state.select(Some(app.display_history_scroll.focus));
+            }
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(Color::Yellow))
+                        .title(format!(" {} — History ", p.name)),
+                )
+                .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                .highlight_symbol("> ");
+            frame.render_stateful_widget(list, body[1], &mut state);
+        }
+
         DisplayScreenMode::CreatingProfile => {
+            let panes = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(6), Constraint::Length(7)])
+                .split(body[1]);
             let d = &app.new_display_draft;
             let items = build_display_creating_items(
-                &d.wallpaper_color, &d.orientation, &d.aspect_ratio,
-                &d.name, None, "",
+                &d.wallpaper_color, &d.frame_style, &d.orientation, &d.aspect_ratio,
+                &d.name, None, "", app.theme.disabled,
             );
             let mut state = ListState::default();
             state.select(Some(d.current_field));
@@ -499,14 +1003,18 @@ fn draw_display_profiles(frame: &mut Frame, app: &App) {
                 )
                 .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
                 .highlight_symbol("> ");
-            frame.render_stateful_widget(list, body[1], &mut state);
+            frame.render_stateful_widget(list, panes[0], &mut state);
+            render_frame_preview(
+                frame, panes[1],
+                FrameStyle::from_str(&d.frame_style), &d.orientation, &d.aspect_ratio,
+            );
         }
 
         DisplayScreenMode::CreatingEditText(buf) => {
             let d = &app.new_display_draft;
             let items = build_display_creating_items(
-                &d.wallpaper_color, &d.orientation, &d.aspect_ratio,
-                &d.name, Some(d.current_field), buf,
+                &d.wallpaper_color, &d.frame_style, &d.orientation, &d.aspect_ratio,
+                &d.name, Some(d.current_field), buf, app.theme.disabled,
             );
             let mut state = ListState::default();
             state.select(Some(d.current_field));
@@ -526,8 +1034,8 @@ fn draw_display_profiles(frame: &mut Frame, app: &App) {
         DisplayScreenMode::CreatingName(buf) => {
             let d = &app.new_display_draft;
             let items = build_display_creating_items(
-                &d.wallpaper_color, &d.orientation, &d.aspect_ratio,
-                buf, Some(4), buf,
+                &d.wallpaper_color, &d.frame_style, &d.orientation, &d.aspect_ratio,
+                buf, Some(4), buf, app.theme.disabled,
             );
             let mut state = ListState::default();
             state.select(Some(4));
@@ -551,19 +1059,33 @@ fn draw_display_profiles(frame: &mut Frame, app: &App) {
             vec![("a", "add"), ("Esc", "back")]
         }
         DisplayScreenMode::Browse => vec![
-            ("↑↓", "select"), ("Enter", "edit"), ("a", "add"), ("d", "delete"), ("Esc", "back"),
+            ("↑↓", "select"), ("Enter", "edit"), ("a", "add"), ("d", "delete"),
+            ("/", "search"), (":", "command"), ("Esc", "back"),
         ],
+        DisplayScreenMode::Searching(_, _) => {
+            vec![("type", "filter"), ("↑↓", "select match"), ("Enter", "open"), ("Esc", "cancel")]
+        }
         DisplayScreenMode::Detail => {
-            vec![("↑↓", "navigate"), ("Enter", "edit"), ("Esc", "back")]
+            vec![("↑↓", "navigate"), ("Enter", "edit"), ("h", "history"), ("Esc", "back")]
+        }
+        DisplayScreenMode::History => {
+            vec![("↑↓", "select"), ("Enter", "restore"), ("Esc", "back")]
         }
         DisplayScreenMode::EditingText(_) => vec![("Enter", "confirm"), ("Esc", "cancel")],
+        DisplayScreenMode::EditingColor(_) => vec![
+            ("type", "hex/name"),
+            ("←→", "channel"),
+            ("↑↓", "nudge"),
+            ("Enter", "confirm"),
+            ("Esc", "cancel"),
+        ],
         DisplayScreenMode::CreatingProfile => {
             vec![("↑↓", "navigate"), ("Enter", "select"), ("Esc", "cancel")]
         }
         DisplayScreenMode::CreatingEditText(_) => vec![("Enter", "confirm"), ("Esc", "cancel")],
         DisplayScreenMode::CreatingName(_) => vec![("Enter", "confirm"), ("Esc", "cancel")],
     };
-    render_footer(frame, footer_area, &footer_hints);
+    render_footer_or_command(frame, footer_area, &footer_hints, app);
 }
 
 // ─── Field item builders ──────────────────────────────────────────────────────
@@ -574,8 +1096,10 @@ fn build_taste_detail_items(
     date_end: Option<i64>,
     is_public_domain: bool,
     kw_count: usize,
+    artist_count: usize,
     editing_field: Option<usize>,
     edit_buf: &str,
+    _disabled: Color,
 ) -> Vec<ListItem<'static>> {
     let ds = if editing_field == Some(0) {
         format!("{}▌", edit_buf)
@@ -589,23 +1113,25 @@ fn build_taste_detail_items(
     };
     let pd = if is_public_domain { "Yes" } else { "No" }.to_string();
     let kw = format!("{}/10", kw_count);
+    let artists = format!("{}/∞", artist_count);
 
     vec![
         ListItem::new(format!(" {:<16}{}", "Date Start", ds)),
         ListItem::new(format!(" {:<16}{}", "Date End", de)),
         ListItem::new(format!(" {:<16}{}", "Public Domain", pd)),
         ListItem::new(format!(" {:<16}{}", "Keywords", kw)),
-        ListItem::new(format!(" {:<16}{}", "Artists", "(coming soon)"))
-            .style(Style::default().fg(Color::DarkGray)),
+        ListItem::new(format!(" {:<16}{}", "Artists", artists)),
     ]
 }
 
-/// Taste profile creation form: 5 rows (same as detail but Name replaces Artists).
+/// Taste profile creation form: 6 rows (same as detail, but Name replaces
+/// the profile title and is entered last, after Artists).
 fn build_taste_creating_items(
     date_start: Option<i64>,
     date_end: Option<i64>,
     is_public_domain: bool,
     kw_count: usize,
+    artist_count: usize,
     name: &str,
     editing_field: Option<usize>,
     edit_buf: &str,
@@ -622,7 +1148,8 @@ fn build_taste_creating_items(
     };
     let pd = if is_public_domain { "Yes" } else { "No" }.to_string();
     let kw = format!("{}/10", kw_count);
-    let nm = if editing_field == Some(4) {
+    let artists = format!("{}/∞", artist_count);
+    let nm = if editing_field == Some(5) {
         format!("{}▌", edit_buf)
     } else if name.is_empty() {
         "(enter name)".to_string()
@@ -635,6 +1162,7 @@ fn build_taste_creating_items(
         ListItem::new(format!(" {:<16}{}", "Date End", de)),
         ListItem::new(format!(" {:<16}{}", "Public Domain", pd)),
         ListItem::new(format!(" {:<16}{}", "Keywords", kw)),
+        ListItem::new(format!(" {:<16}{}", "Artists", artists)),
         ListItem::new(format!(" {:<16}{}", "Name", nm)),
     ]
 }
@@ -642,16 +1170,23 @@ fn build_taste_creating_items(
 /// Display profile detail view: 4 rows (Color, Frame Style, Orientation, Aspect Ratio).
 fn build_display_detail_items(
     wallpaper_color: &str,
+    frame_style: &str,
     orientation: &str,
     aspect_ratio: &str,
     editing_field: Option<usize>,
     edit_buf: &str,
+    _disabled: Color,
 ) -> Vec<ListItem<'static>> {
     let color = if editing_field == Some(0) {
         format!("{}▌", edit_buf)
     } else {
         wallpaper_color.to_string()
     };
+    let frame = format!(
+        "{} {}",
+        FrameStyle::from_str(frame_style).corner_preview(),
+        FrameStyle::from_str(frame_style).label()
+    );
     let orient = if orientation == "horizontal" { "Horizontal" } else { "Vertical" }.to_string();
     let ratio = if editing_field == Some(3) {
         format!("{}▌", edit_buf)
@@ -661,27 +1196,108 @@ fn build_display_detail_items(
 
     vec![
         ListItem::new(format!(" {:<16}{}", "Color", color)),
-        ListItem::new(format!(" {:<16}{}", "Frame Style", "(coming soon)"))
-            .style(Style::default().fg(Color::DarkGray)),
+        ListItem::new(format!(" {:<16}{}", "Frame Style", frame)),
         ListItem::new(format!(" {:<16}{}", "Orientation", orient)),
         ListItem::new(format!(" {:<16}{}", "Aspect Ratio", ratio)),
     ]
 }
 
+/// Mock preview of the chosen frame style around the chosen orientation: a
+/// small bordered box using the style's own box-drawing glyphs, stretched
+/// wide or tall to hint at the aspect ratio without needing real artwork.
+fn render_frame_preview(frame: &mut Frame, area: Rect, style: FrameStyle, orientation: &str, aspect_ratio: &str) {
+    let (tl, tr, bl, br, h, v) = style.border_glyphs();
+    let wide = orientation != "vertical";
+    let inner_w = if wide { area.width.saturating_sub(6) } else { area.width.saturating_sub(12) };
+    let inner_h = if wide { 1 } else { 3 };
+    let box_w = (inner_w.max(4)) as usize;
+    let top = format!("{}{}{}", tl, h.to_string().repeat(box_w), tr);
+    let mut lines = vec![Line::from(top.clone())];
+    for _ in 0..inner_h {
+        lines.push(Line::from(format!("{}{}{}", v, " ".repeat(box_w), v)));
+    }
+    lines.push(Line::from(format!("{}{}{}", bl, h.to_string().repeat(box_w), br)));
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!("{} · {}", style.label(), aspect_ratio)));
+    let preview = Paragraph::new(lines).alignment(Alignment::Center).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .title(" Preview "),
+    );
+    frame.render_widget(preview, area);
+}
+
+/// Live color-picker panel for the Color detail field: typed hex/name text,
+/// the R/G/B channels with the arrow-nudge target highlighted, and a swatch
+/// block showing the parsed color before it's saved.
+fn render_color_picker(frame: &mut Frame, area: Rect, profile_name: &str, picker: &ColorPickerState) {
+    let channel_span = |i: usize, value: u8| {
+        let style = if i == picker.channel {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        Span::styled(format!("{}:{:<3} ", ["R", "G", "B"][i], value), style)
+    };
+    let (r, g, b) = picker.rgb.unwrap_or((0, 0, 0));
+    let mut lines = vec![
+        Line::from(format!("{}▌", picker.buf)),
+        Line::from(""),
+        Line::from(vec![channel_span(0, r), channel_span(1, g), channel_span(2, b)]),
+        Line::from(""),
+    ];
+    if picker.rgb.is_none() {
+        lines.push(Line::from(Span::styled(
+            "not a valid #rrggbb hex or color name",
+            Style::default().fg(Color::Red),
+        )));
+    } else {
+        lines.push(Line::from("◀ ▶ channel   ▲ ▼ nudge   Enter save"));
+    }
+    let panel = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(format!(" {} — Color ", profile_name)),
+    );
+    frame.render_widget(panel, area);
+
+    if picker.rgb.is_some() && area.height > 4 {
+        let swatch_area = Rect {
+            x: area.x + 2,
+            y: area.y + area.height - 2,
+            width: area.width.saturating_sub(4),
+            height: 1,
+        };
+        let swatch = Paragraph::new("").style(Style::default().bg(Color::Rgb(r, g, b)));
+        frame.render_widget(swatch, swatch_area);
+    }
+}
+
 /// Display profile creation form: 5 rows (same as detail + Name at bottom).
 fn build_display_creating_items(
     wallpaper_color: &str,
+    frame_style: &str,
     orientation: &str,
     aspect_ratio: &str,
     name: &str,
     editing_field: Option<usize>,
     edit_buf: &str,
+    _disabled: Color,
 ) -> Vec<ListItem<'static>> {
     let color = if editing_field == Some(0) {
         format!("{}▌", edit_buf)
     } else {
         wallpaper_color.to_string()
     };
+    let frame = format!(
+        "{} {}",
+        FrameStyle::from_str(frame_style).corner_preview(),
+        FrameStyle::from_str(frame_style).label()
+    );
     let orient = if orientation == "horizontal" { "Horizontal" } else { "Vertical" }.to_string();
     let ratio = if editing_field == Some(3) {
         format!("{}▌", edit_buf)
@@ -698,22 +1314,66 @@ fn build_display_creating_items(
 
     vec![
         ListItem::new(format!(" {:<16}{}", "Color", color)),
-        ListItem::new(format!(" {:<16}{}", "Frame Style", "(coming soon)"))
-            .style(Style::default().fg(Color::DarkGray)),
+        ListItem::new(format!(" {:<16}{}", "Frame Style", frame)),
         ListItem::new(format!(" {:<16}{}", "Orientation", orient)),
         ListItem::new(format!(" {:<16}{}", "Aspect Ratio", ratio)),
         ListItem::new(format!(" {:<16}{}", "Name", nm)),
     ]
 }
 
+/// Render a list of prior profile states, newest first, for the History
+/// mode of either profile screen: `fields` renders the type-specific part
+/// of each entry below a shared "kind — age" line.
+fn history_list_items<T: HistoryEntry>(
+    entries: &[T],
+    fields: impl Fn(&T) -> Vec<String>,
+) -> Vec<ListItem<'static>> {
+    if entries.is_empty() {
+        return vec![ListItem::new("(no history yet)").style(Style::default().fg(Color::DarkGray))];
+    }
+    let now = crate::frecency::now_unix();
+    entries
+        .iter()
+        .map(|e| {
+            let mut lines =
+                vec![Line::from(format!(" {} — {}", e.change_kind(), relative_age(now, e.changed_at())))];
+            lines.extend(
+                fields(e).into_iter().map(|f| Line::from(format!("   {}", f))),
+            );
+            ListItem::new(lines)
+        })
+        .collect()
+}
+
+/// Coarse human-readable age ("3m ago", "2d ago") for a history timestamp.
+fn relative_age(now: i64, changed_at: i64) -> String {
+    let age = (now - changed_at).max(0);
+    if age < 60 {
+        format!("{}s ago", age)
+    } else if age < 3_600 {
+        format!("{}m ago", age / 60)
+    } else if age < 86_400 {
+        format!("{}h ago", age / 3_600)
+    } else {
+        format!("{}d ago", age / 86_400)
+    }
+}
+
 /// Shared keyword picker used by both SelectingKeywords and CreatingSelectKeywords.
 fn render_keyword_picker(
     frame: &mut Frame,
     area: Rect,
     available: &[(i64, String)],
+    visible: &[usize],
     selected: &[String],
     cursor: usize,
+    filter_query: Option<&str>,
+    theme: &Theme,
 ) {
+    let title = match filter_query {
+        Some(query) => format!(" Select Keywords — /{} ", query),
+        None => " Select Keywords ".to_string(),
+    };
     if available.is_empty() {
         let msg = Paragraph::new("(no keywords in database yet)")
             .alignment(Alignment::Center)
@@ -721,16 +1381,30 @@ fn render_keyword_picker(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(Color::Yellow))
-                    .title(" Select Keywords "),
+                    .border_style(Style::default().fg(theme.accent))
+                    .title(title),
+            );
+        frame.render_widget(msg, area);
+    } else if visible.is_empty() {
+        let msg = Paragraph::new("(no matches)")
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.accent))
+                    .title(title),
             );
         frame.render_widget(msg, area);
     } else {
-        let items: Vec<ListItem> = available
+        let items: Vec<ListItem> = visible
             .iter()
-            .map(|(_, kw)| {
+            .map(|&i| {
+                let (_, kw) = &available[i];
                 let prefix = if selected.contains(kw) { "[✓] " } else { "[ ] " };
-                ListItem::new(format!("{}{}", prefix, kw))
+                let mut spans = vec![Span::raw(prefix)];
+                spans.extend(highlighted_spans(kw, filter_query, Style::default()));
+                ListItem::new(Line::from(spans))
             })
             .collect();
         let mut state = ListState::default();
@@ -740,20 +1414,130 @@ fn render_keyword_picker(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(Color::Yellow))
-                    .title(" Select Keywords "),
+                    .border_style(Style::default().fg(theme.accent))
+                    .title(title),
             )
-            .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .highlight_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
             .highlight_symbol("> ");
         frame.render_stateful_widget(list, area, &mut state);
+        render_scrollbar(frame, area, visible.len(), cursor);
     }
 }
 
+/// Shared artist picker used by SelectingArtists, mirroring
+/// [`render_keyword_picker`]. `available` is `(id, name, aliases)`; the
+/// search bar (see `app::filter_artists`) matches both name and aliases,
+/// but only the name is bolded here since aliases aren't shown in the row.
+fn render_artist_picker(
+    frame: &mut Frame,
+    area: Rect,
+    available: &[(i64, String, String)],
+    visible: &[usize],
+    selected: &[String],
+    cursor: usize,
+    filter_query: Option<&str>,
+    theme: &Theme,
+) {
+    let title = match filter_query {
+        Some(query) => format!(" Select Artists — /{} ", query),
+        None => " Select Artists ".to_string(),
+    };
+    if available.is_empty() {
+        let msg = Paragraph::new("(no artists in database yet)")
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.accent))
+                    .title(title),
+            );
+        frame.render_widget(msg, area);
+    } else if visible.is_empty() {
+        let msg = Paragraph::new("(no matches)")
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.accent))
+                    .title(title),
+            );
+        frame.render_widget(msg, area);
+    } else {
+        let items: Vec<ListItem> = visible
+            .iter()
+            .map(|&i| {
+                let (_, name, _) = &available[i];
+                let prefix = if selected.contains(name) { "[✓] " } else { "[ ] " };
+                let mut spans = vec![Span::raw(prefix)];
+                spans.extend(highlighted_spans(name, filter_query, Style::default()));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let mut state = ListState::default();
+        state.select(Some(cursor));
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.accent))
+                    .title(title),
+            )
+            .highlight_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, area, &mut state);
+        render_scrollbar(frame, area, visible.len(), cursor);
+    }
+}
+
+/// Spans for `text` with any fuzzy-matched characters against `query`
+/// bolded on top of `base_style` — shared by every type-to-filter picker
+/// so the emphasis looks the same everywhere. With no active query (or an
+/// empty one) this is just `text` in `base_style`, unsplit.
+fn highlighted_spans(text: &str, query: Option<&str>, base_style: Style) -> Vec<Span<'static>> {
+    let matched = match query {
+        Some(q) if !q.is_empty() => crate::app::fuzzy_match_positions(q, text),
+        _ => Vec::new(),
+    };
+    if matched.is_empty() {
+        return vec![Span::styled(text.to_string(), base_style)];
+    }
+    text.chars()
+        .enumerate()
+        .map(|(i, ch)| {
+            let style = if matched.contains(&i) {
+                base_style.add_modifier(Modifier::BOLD)
+            } else {
+                base_style
+            };
+            Span::styled(ch.to_string(), style)
+        })
+        .collect()
+}
+
+/// Thin position/proportion gutter on a list block's right edge — inset by
+/// one row top and bottom so it tracks the border rather than overdrawing
+/// its corners. A no-op for an empty list.
+fn render_scrollbar(frame: &mut Frame, area: Rect, total: usize, position: usize) {
+    if total == 0 {
+        return;
+    }
+    let mut state = ScrollbarState::new(total).position(position);
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .track_symbol(Some("│"))
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_stateful_widget(scrollbar, area.inner(Margin { horizontal: 0, vertical: 1 }), &mut state);
+}
+
 // ─── Build wizard ─────────────────────────────────────────────────────────────
 
-fn draw_build(frame: &mut Frame, app: &App) {
+fn draw_build(frame: &mut Frame, app: &mut App) {
     let (header_area, body_area, footer_area) = base_layout(frame);
-    render_header(frame, header_area, "Build Wallpaper Gallery");
+    render_header(frame, app, header_area, "Build Wallpaper Gallery");
 
     let body = Layout::default()
         .direction(Direction::Vertical)
@@ -773,19 +1557,20 @@ fn draw_build(frame: &mut Frame, app: &App) {
         if *step == app.build_step {
             step_spans.push(Span::styled(
                 *label,
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD),
             ));
         } else {
-            step_spans.push(Span::styled(*label, Style::default().fg(Color::DarkGray)));
+            step_spans.push(Span::styled(*label, Style::default().fg(app.theme.border)));
         }
     }
 
+    let border_type = app.theme.border_type;
     let step_indicator = Paragraph::new(Line::from(step_spans))
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::BOTTOM)
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(app.theme.border)),
         );
     frame.render_widget(step_indicator, body[0]);
 
@@ -797,31 +1582,55 @@ fn draw_build(frame: &mut Frame, app: &App) {
                     .block(
                         Block::default()
                             .borders(Borders::ALL)
-                            .border_type(BorderType::Rounded)
-                            .border_style(Style::default().fg(Color::DarkGray))
+                            .border_type(border_type)
+                            .border_style(Style::default().fg(app.theme.border))
                             .title(" Select Taste Profile "),
                     );
                 frame.render_widget(msg, body[1]);
             } else {
-                let items: Vec<ListItem> = app
-                    .taste_profiles
-                    .iter()
-                    .map(|p| ListItem::new(p.name.as_str()))
-                    .collect();
+                let (visible_indices, query, title): (Vec<usize>, Option<&str>, String) =
+                    match &app.build_search {
+                        Some((query, matches)) => (
+                            matches.clone(),
+                            Some(query.as_str()),
+                            format!(" Select Taste Profile — /{} ", query),
+                        ),
+                        None => (
+                            (0..app.taste_profiles.len()).collect(),
+                            None,
+                            " Select Taste Profile ".to_string(),
+                        ),
+                    };
+                let items: Vec<ListItem> = if visible_indices.is_empty() {
+                    vec![ListItem::new("(no matches)").style(Style::default().fg(app.theme.border))]
+                } else {
+                    visible_indices
+                        .iter()
+                        .map(|&i| {
+                            let spans = highlighted_spans(
+                                &app.taste_profiles[i].name, query, Style::default(),
+                            );
+                            ListItem::new(Line::from(spans))
+                        })
+                        .collect()
+                };
                 let mut state = ListState::default();
-                state.select(Some(app.build_taste_idx));
+                if !visible_indices.is_empty() {
+                    state.select(Some(app.build_taste_idx));
+                }
                 let list = List::new(items)
                     .block(
                         Block::default()
                             .borders(Borders::ALL)
-                            .border_type(BorderType::Rounded)
-                            .border_style(Style::default().fg(Color::DarkGray))
-                            .title(" Select Taste Profile "),
+                            .border_type(border_type)
+                            .border_style(Style::default().fg(app.theme.border))
+                            .title(title),
                     )
                     .highlight_style(
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                        Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD),
                     )
                     .highlight_symbol("> ");
+                app.build_list_rect = body[1];
                 frame.render_stateful_widget(list, body[1], &mut state);
             }
         }
@@ -832,50 +1641,325 @@ fn draw_build(frame: &mut Frame, app: &App) {
                     .block(
                         Block::default()
                             .borders(Borders::ALL)
-                            .border_type(BorderType::Rounded)
-                            .border_style(Style::default().fg(Color::DarkGray))
+                            .border_type(border_type)
+                            .border_style(Style::default().fg(app.theme.border))
                             .title(" Select Display Profile "),
                     );
                 frame.render_widget(msg, body[1]);
             } else {
-                let items: Vec<ListItem> = app
-                    .display_profiles
-                    .iter()
-                    .map(|p| ListItem::new(p.name.as_str()))
-                    .collect();
+                let (visible_indices, query, title): (Vec<usize>, Option<&str>, String) =
+                    match &app.build_search {
+                        Some((query, matches)) => (
+                            matches.clone(),
+                            Some(query.as_str()),
+                            format!(" Select Display Profile — /{} ", query),
+                        ),
+                        None => (
+                            (0..app.display_profiles.len()).collect(),
+                            None,
+                            " Select Display Profile ".to_string(),
+                        ),
+                    };
+                let items: Vec<ListItem> = if visible_indices.is_empty() {
+                    vec![ListItem::new("(no matches)").style(Style::default().fg(app.theme.border))]
+                } else {
+                    visible_indices
+                        .iter()
+                        .map(|&i| {
+                            let spans = highlighted_spans(
+                                &app.display_profiles[i].name, query, Style::default(),
+                            );
+                            ListItem::new(Line::from(spans))
+                        })
+                        .collect()
+                };
                 let mut state = ListState::default();
-                state.select(Some(app.build_display_idx));
+                if !visible_indices.is_empty() {
+                    state.select(Some(app.build_display_idx));
+                }
                 let list = List::new(items)
                     .block(
                         Block::default()
                             .borders(Borders::ALL)
-                            .border_type(BorderType::Rounded)
-                            .border_style(Style::default().fg(Color::DarkGray))
-                            .title(" Select Display Profile "),
+                            .border_type(border_type)
+                            .border_style(Style::default().fg(app.theme.border))
+                            .title(title),
                     )
                     .highlight_style(
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                        Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD),
                     )
                     .highlight_symbol("> ");
+                app.build_list_rect = body[1];
                 frame.render_stateful_widget(list, body[1], &mut state);
             }
         }
+        BuildStep::ConfirmStage => {
+            draw_confirm_stage(frame, app, body[1]);
+        }
         BuildStep::PickOutputDir => {
-            let input_text = format!("{}▌", app.build_output_dir);
-            let input = Paragraph::new(input_text).block(
+            let panes = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(3)])
+                .split(body[1]);
+
+            let status = dirbrowse::path_status(&app.build_output_dir);
+            let (status_text, status_color) = match status {
+                dirbrowse::PathStatus::Ready => ("exists, writable", app.theme.accent),
+                dirbrowse::PathStatus::NotWritable => ("exists, read-only", app.theme.warning),
+                dirbrowse::PathStatus::Missing => ("does not exist yet", app.theme.disabled),
+            };
+            let input = Paragraph::new(Line::from(vec![
+                Span::raw(format!("{}▌  ", app.build_output_dir)),
+                Span::styled(status_text, Style::default().fg(status_color)),
+            ]))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(border_type)
+                    .border_style(Style::default().fg(app.theme.accent))
+                    .title(" Output directory "),
+            );
+            frame.render_widget(input, panes[0]);
+
+            app.build_list_rect = panes[1];
+            if let Some(name) = &app.build_new_folder {
+                let prompt = Paragraph::new(Line::from(Span::raw(format!("{}▌", name))))
+                    .style(Style::default().fg(app.theme.editing_cursor))
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_type(border_type)
+                            .border_style(Style::default().fg(app.theme.accent))
+                            .title(" Create subdirectory "),
+                    );
+                frame.render_widget(prompt, panes[1]);
+            } else {
+                let items: Vec<ListItem> = if app.build_dir_entries.is_empty() {
+                    vec![ListItem::new("(no subfolders here)").style(Style::default().fg(app.theme.disabled))]
+                } else {
+                    app.build_dir_entries
+                        .iter()
+                        .map(|name| ListItem::new(format!("{}/", name)))
+                        .collect()
+                };
+                let mut state = ListState::default();
+                if !app.build_dir_entries.is_empty() {
+                    state.select(Some(app.build_dir_scroll.focus));
+                }
+                let list = List::new(items)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_type(border_type)
+                            .border_style(Style::default().fg(app.theme.border))
+                            .title(" Subfolders — Tab/→ enter, ← up, Ctrl+N new "),
+                    )
+                    .highlight_style(Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD))
+                    .highlight_symbol("> ");
+                frame.render_stateful_widget(list, panes[1], &mut state);
+            }
+        }
+    }
+
+    let footer_hints: &[(&str, &str)] = if app.build_new_folder.is_some() {
+        &[("type", "name"), ("Enter", "create"), ("Esc", "cancel")]
+    } else {
+        match (app.build_step, app.build_search.is_some()) {
+            (_, true) => &[("↑↓", "select"), ("Enter", "choose"), ("Esc", "cancel search")],
+            (BuildStep::PickOutputDir, _) => &[
+                ("type", "edit path"),
+                ("Tab/→", "enter folder"),
+                ("←", "up a folder"),
+                ("Ctrl+N", "new folder"),
+                ("Enter", "build"),
+                ("Esc", "back"),
+            ],
+            (BuildStep::ConfirmStage, _) => &[("Enter", "continue"), ("Esc", "back")],
+            (BuildStep::PickTaste, _) if !app.stage.paths_or_ids.is_empty() => &[
+                ("↑↓", "select"),
+                ("Enter", "next"),
+                ("/", "search"),
+                ("g", "use staged"),
+                ("Esc", "back"),
+            ],
+            _ => &[("↑↓", "select"), ("Enter", "next"), ("/", "search"), ("Esc", "back")],
+        }
+    };
+    render_footer_or_command(frame, footer_area, footer_hints, app);
+}
+
+// ─── Theme picker ──────────────────────────────────────────────────────────────
+
+fn theme_preview_lines(theme: &crate::theme::Theme) -> Vec<Line<'static>> {
+    vec![
+        Line::from(Span::styled("accent", Style::default().fg(theme.accent))),
+        Line::from(Span::styled("border", Style::default().fg(theme.border))),
+        Line::from(Span::styled("disabled", Style::default().fg(theme.disabled))),
+        Line::from(Span::styled("error", Style::default().fg(theme.error))),
+        Line::from(Span::styled(
+            "selected",
+            Style::default().fg(theme.selected_fg).bg(theme.selected_bg),
+        )),
+    ]
+}
+
+fn draw_theme(frame: &mut Frame, app: &mut App) {
+    let (header_area, body_area, footer_area) = base_layout(frame);
+    render_header(frame, app, header_area, "Theme");
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(22), Constraint::Min(0)])
+        .split(body_area.inner(Margin { horizontal: 2, vertical: 1 }));
+
+    match &app.theme_mode {
+        ThemeScreenMode::Browse => {
+            let names = app.theme_names();
+            let items: Vec<ListItem> = names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    if i < Theme::BUILTIN_NAMES.len() {
+                        ListItem::new(name.as_str())
+                    } else {
+                        ListItem::new(format!("{} (custom)", name))
+                    }
+                })
+                .collect();
+            let mut state = ListState::default();
+            state.select(Some(app.theme_selected));
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(app.theme.border))
+                        .title(" Themes "),
+                )
+                .highlight_style(
+                    Style::default()
+                        .fg(app.theme.selected_fg)
+                        .bg(app.theme.selected_bg)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("> ");
+            frame.render_stateful_widget(list, body[0], &mut state);
+
+            let highlighted = app.resolve_theme(app.theme_selected);
+            let preview = Paragraph::new(theme_preview_lines(&highlighted)).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(app.theme.border))
+                    .title(format!(" {} ", highlighted.name)),
+            );
+            frame.render_widget(preview, body[1]);
+        }
+
+        ThemeScreenMode::Detail => {
+            let items: Vec<ListItem> = Theme::ROLE_NAMES
+                .iter()
+                .enumerate()
+                .map(|(i, role)| {
+                    ListItem::new(format!(" {:<16}{}", role, app.theme_draft.role_hex(i)))
+                })
+                .collect();
+            let mut state = ListState::default();
+            state.select(Some(app.theme_detail_field));
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(Color::Yellow))
+                        .title(format!(" {} ", app.theme_draft.name)),
+                )
+                .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                .highlight_symbol("> ");
+            frame.render_stateful_widget(list, body[0], &mut state);
+
+            let preview = Paragraph::new(theme_preview_lines(&app.theme_draft)).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::DarkGray))
+                    .title(" Preview "),
+            );
+            frame.render_widget(preview, body[1]);
+        }
+
+        ThemeScreenMode::EditingColor(picker) => {
+            let items: Vec<ListItem> = Theme::ROLE_NAMES
+                .iter()
+                .enumerate()
+                .map(|(i, role)| {
+                    ListItem::new(format!(" {:<16}{}", role, app.theme_draft.role_hex(i)))
+                })
+                .collect();
+            let mut state = ListState::default();
+            state.select(Some(app.theme_detail_field));
+            let list = List::new(items).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::DarkGray))
+                    .title(format!(" {} ", app.theme_draft.name)),
+            );
+            frame.render_stateful_widget(list, body[0], &mut state);
+            render_color_picker(
+                frame,
+                body[1],
+                Theme::ROLE_NAMES[app.theme_detail_field],
+                picker,
+            );
+        }
+
+        ThemeScreenMode::Naming(buf) => {
+            let items: Vec<ListItem> = Theme::ROLE_NAMES
+                .iter()
+                .enumerate()
+                .map(|(i, role)| {
+                    ListItem::new(format!(" {:<16}{}", role, app.theme_draft.role_hex(i)))
+                })
+                .collect();
+            let list = List::new(items).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::DarkGray))
+                    .title(" Theme "),
+            );
+            frame.render_widget(list, body[0]);
+
+            let input = Paragraph::new(format!("{}▌", buf)).block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(Style::default().fg(Color::Yellow))
-                    .title(" Output directory "),
+                    .title(" Save as "),
             );
             frame.render_widget(input, body[1]);
         }
     }
 
-    let footer_hints: &[(&str, &str)] = match app.build_step {
-        BuildStep::PickOutputDir => &[("Enter", "build"), ("Esc", "back"), ("Backspace", "edit")],
-        _ => &[("↑↓", "select"), ("Enter", "next"), ("Esc", "back")],
+    let footer_hints: &[(&str, &str)] = match &app.theme_mode {
+        ThemeScreenMode::Browse => {
+            &[("↑↓", "select"), ("Enter", "apply"), ("e", "edit"), ("Esc", "back")]
+        }
+        ThemeScreenMode::Detail => &[
+            ("↑↓", "role"),
+            ("Enter", "edit color"),
+            ("s", "save as..."),
+            ("Esc", "cancel"),
+        ],
+        ThemeScreenMode::EditingColor(_) => &[
+            ("type", "hex/name"),
+            ("←→", "channel"),
+            ("↑↓", "nudge"),
+            ("Enter", "confirm"),
+            ("Esc", "cancel"),
+        ],
+        ThemeScreenMode::Naming(_) => &[("type", "name"), ("Enter", "save"), ("Esc", "back")],
     };
-    render_footer(frame, footer_area, footer_hints);
+    render_footer_or_command(frame, footer_area, footer_hints, app);
 }
@@ -0,0 +1,84 @@
+//! Background filesystem watcher that flags the cached profile lists as
+//! stale when the on-disk database changes out from under this process
+//! (e.g. a second instance of artgg, or a restore/import run from the
+//! CLI while the TUI is open). Mirrors `artwork::ArtworkWorker`'s
+//! worker-thread-plus-shared-cell shape, but the payload here is just
+//! "something changed" rather than a value to resolve, and the source of
+//! events is `notify`'s own channel instead of a mailbox the render loop
+//! posts into.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+/// How long to keep coalescing events before flipping the dirty flag —
+/// long enough that a burst of writes (SQLite's journal/wal churn across
+/// a single transaction, or a multi-row import) collapses into one
+/// reload, short enough that it still feels immediate.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Handle the render loop holds. Keeps the `notify::Watcher` alive for as
+/// long as this is alive — dropping it tears down the underlying OS
+/// watch, so `main::run` keeps this on `App` for the whole session.
+pub struct ProfileWatcher {
+    dirty: Arc<AtomicBool>,
+    _watcher: RecommendedWatcher,
+}
+
+impl ProfileWatcher {
+    /// Watch `dir` (the app's data directory) for changes, debouncing
+    /// bursts on a background thread. Returns `None` if the watch can't
+    /// be established (missing directory, unsupported platform backend,
+    /// inotify instance limit, ...) — live-reload is a convenience, not
+    /// something worth failing startup over.
+    pub fn spawn(dir: &str) -> Option<Self> {
+        let dirty = Arc::new(AtomicBool::new(false));
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok() {
+                let _ = tx.send(());
+            }
+        })
+        .ok()?;
+        watcher.watch(Path::new(dir), RecursiveMode::NonRecursive).ok()?;
+
+        let worker_dirty = Arc::clone(&dirty);
+        thread::spawn(move || debounce(rx, worker_dirty));
+
+        Some(Self { dirty, _watcher: watcher })
+    }
+
+    /// Read and clear the dirty flag. Call this once per main-loop
+    /// iteration; a `true` result means the caller should reload its
+    /// cached profile vectors (and re-clamp whatever indexes into them)
+    /// before the next draw.
+    pub fn take_dirty(&self) -> bool {
+        self.dirty.swap(false, Ordering::SeqCst)
+    }
+}
+
+/// Coalesce a burst of raw filesystem events into a single dirty flag:
+/// wait for the first one, then keep draining for `DEBOUNCE` after each
+/// one seen so a rapid sequence of writes only flips the flag once
+/// instead of once per underlying event.
+fn debounce(rx: Receiver<()>, dirty: Arc<AtomicBool>) {
+    loop {
+        if rx.recv().is_err() {
+            return;
+        }
+        loop {
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(()) => continue,
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+        dirty.store(true, Ordering::SeqCst);
+    }
+}